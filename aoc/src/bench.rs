@@ -0,0 +1,271 @@
+// time a day's parse, part 1 and part 2 separately, in-process, so we measure the solvers and
+// not process startup or file IO. results are appended to a history file so each run can be
+// compared with the one before it
+
+use std::fmt;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{Answer, Solution};
+use common::parse::ParseError;
+
+#[derive(Clone, Copy)]
+pub struct Settings {
+    pub warmup : usize,
+    pub samples: usize,
+
+    // stop sampling a slow stage early once it's used up this much time
+    pub budget : Duration
+}
+
+// the smallest number of samples we'll settle for when a stage blows its budget
+const MIN_SAMPLES: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub median : Duration,
+    pub mad    : Duration,     // median absolute deviation from the median
+    pub min    : Duration,
+    pub max    : Duration,
+    pub samples: usize
+}
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+// stats for each stage, in STAGES order. None for a part that isn't solved yet
+pub type Timings = [Option<Stats>; 3];
+
+pub fn bench<S: Solution>(text: &str, settings: &Settings) -> Result<Timings, ParseError> {
+    // a bad input fails fast rather than timing how long it takes to fail
+    let input = S::parse(text)?;
+    let parse = measure(settings, || S::parse(black_box(text)));
+
+    let part1 = S::part1(&input).is_solved()
+                    .then(|| measure(settings, || S::part1(black_box(&input)).to_string()));
+
+    let part2 = S::part2(&input).is_solved()
+                    .then(|| measure(settings, || S::part2(black_box(&input)).to_string()));
+
+    Ok([Some(parse), part1, part2])
+}
+
+fn measure<T>(settings: &Settings, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..settings.warmup {
+        black_box(f());
+    }
+
+    let started = Instant::now();
+    let mut samples = vec![];
+
+    while samples.len() < settings.samples {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+
+        if samples.len() >= MIN_SAMPLES && started.elapsed() > settings.budget {
+            break
+        }
+    }
+
+    stats(samples)
+}
+
+pub fn stats(mut samples: Vec<Duration>) -> Stats {
+    samples.sort();
+    let median = median_of(&samples);
+
+    let mut deviations: Vec<Duration> = samples.iter()
+                                               .map(|&sample| sample.abs_diff(median))
+                                               .collect();
+    deviations.sort();
+
+    Stats {
+        median,
+        mad    : median_of(&deviations),
+        min    : samples[0],
+        max    : samples[samples.len() - 1],
+        samples: samples.len()
+    }
+}
+
+fn median_of(sorted: &[Duration]) -> Duration {
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+    }
+}
+
+
+/* History */
+
+// one line per stage per run in the file: when, which day and input, and the stats in nanoseconds
+pub struct Record {
+    pub day   : u8,
+    pub input : String,
+    pub stage : String,
+    pub median: Duration,
+    pub mad   : Duration
+}
+
+pub fn history_path() -> PathBuf {
+    common::root().join("bench-history.tsv")
+}
+
+pub fn load_history() -> Vec<Record> {
+    std::fs::read_to_string(history_path())
+        .unwrap_or_default()
+        .lines()
+        .filter_map(parse_record)
+        .collect()
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let fields: Vec<&str> = line.split('\t').collect();
+
+    let [time, day, input, stage, median, mad] = fields[..] else { return None };
+
+    time.parse::<u64>().ok()?;
+
+    Some(Record {
+        day   : day.parse().ok()?,
+        input : input.to_string(),
+        stage : stage.to_string(),
+        median: Duration::from_nanos(median.parse().ok()?),
+        mad   : Duration::from_nanos(mad.parse().ok()?)
+    })
+}
+
+pub fn append_history(day: u8, input: &str, timings: &Timings) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_path())?;
+
+    for (stage, stats) in STAGES.iter().zip(timings) {
+        if let Some(stats) = stats {
+            writeln!(file, "{time}\t{day}\t{input}\t{stage}\t{}\t{}",
+                     stats.median.as_nanos(), stats.mad.as_nanos())?;
+        }
+    }
+
+    Ok(())
+}
+
+// the most recent recorded run of this stage
+pub fn baseline<'a>(history: &'a [Record], day: u8, input: &str, stage: &str) -> Option<&'a Record> {
+    history.iter()
+           .rev()
+           .find(|record| record.day == day && record.input == input && record.stage == stage)
+}
+
+// how a new median compares with the baseline's. a change only counts as a regression if
+// it's more than 10% and well outside the noise of both runs
+pub fn compare(stats: &Stats, baseline: &Record) -> String {
+    let old = baseline.median.as_secs_f64();
+    let new = stats.median.as_secs_f64();
+    let change = (new - old) / old * 100.0;
+
+    let noise = 3.0 * stats.mad.max(baseline.mad).as_secs_f64();
+
+    if change > 10.0 && new - old > noise {
+        format!("{change:+.1}% REGRESSED")
+    } else {
+        format!("{change:+.1}%")
+    }
+}
+
+
+/* Formatting */
+
+pub struct Human(pub Duration);
+
+impl fmt::Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+
+        let text = if nanos < 1e3 {
+            format!("{nanos:.0}ns")
+        } else if nanos < 1e6 {
+            format!("{:.1}µs", nanos / 1e3)
+        } else if nanos < 1e9 {
+            format!("{:.2}ms", nanos / 1e6)
+        } else {
+            format!("{:.2}s", nanos / 1e9)
+        };
+
+        f.pad(&text)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = stats(micros(&[5, 1, 3, 100, 4]));
+
+        assert_eq!(Duration::from_micros(4), stats.median);
+        assert_eq!(Duration::from_micros(1), stats.mad);      // deviations 1,3,1,96,0
+        assert_eq!(Duration::from_micros(1), stats.min);
+        assert_eq!(Duration::from_micros(100), stats.max);
+        assert_eq!(5, stats.samples);
+
+        assert_eq!(Duration::from_micros(3), super::stats(micros(&[1, 2, 4, 9])).median);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let record = parse_record("1700000000\t4\tinput\tpart2\t1500\t20").unwrap();
+
+        assert_eq!(4, record.day);
+        assert_eq!("part2", record.stage);
+        assert_eq!(Duration::from_nanos(1500), record.median);
+        assert!(parse_record("garbage").is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Record {
+            day   : 4,
+            input : "input".to_string(),
+            stage : "part1".to_string(),
+            median: Duration::from_micros(100),
+            mad   : Duration::from_micros(1)
+        };
+
+        let with_median = |micros| Stats {
+            median : Duration::from_micros(micros),
+            mad    : Duration::from_micros(1),
+            min    : Duration::ZERO,
+            max    : Duration::ZERO,
+            samples: 10
+        };
+
+        assert_eq!("-50.0%", compare(&with_median(50), &baseline));
+        assert_eq!("+5.0%", compare(&with_median(105), &baseline));
+        assert_eq!("+50.0% REGRESSED", compare(&with_median(150), &baseline));
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!("850ns", Human(Duration::from_nanos(850)).to_string());
+        assert_eq!("12.3µs", Human(Duration::from_nanos(12_345)).to_string());
+        assert_eq!("1.50ms", Human(Duration::from_micros(1500)).to_string());
+        assert_eq!("2.00s", Human(Duration::from_secs(2)).to_string());
+    }
+}
@@ -0,0 +1,223 @@
+// which puzzles are unlocked at a given instant. each puzzle unlocks at midnight US-Eastern on
+// its day in December. the event runs in December only, so US-Eastern is always EST (UTC-5)
+// and there's no daylight saving to worry about
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// the year this repo is solving
+pub const YEAR: u16 = common::YEAR;
+
+const FIRST_YEAR: u16 = 2015;
+const UNLOCK_OFFSET_SECS: i64 = 5 * 60 * 60;   // midnight EST is 05:00 UTC
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PuzzleDay {
+    pub year: u16,
+    pub day : u8
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CalendarError {
+    NoSuchYear(u16),
+    NoSuchDay { year: u16, day: u8, days: u8 },
+    NotUnlocked { year: u16, day: u8, unlocks_in: Duration },
+    NoPuzzleToday { date: (i64, u32, u32) }
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalendarError::NoSuchYear(year) =>
+                write!(f, "there was no Advent of Code in {year}, the first one was {FIRST_YEAR}"),
+
+            CalendarError::NoSuchDay { year, day, days } =>
+                write!(f, "{year} only has {days} days, there's no day {day}"),
+
+            CalendarError::NotUnlocked { year, day, unlocks_in } =>
+                write!(f, "{year} day {day} isn't unlocked yet, it unlocks at midnight US-Eastern in {}",
+                       format_duration(*unlocks_in)),
+
+            CalendarError::NoPuzzleToday { date: (year, month, day) } =>
+                write!(f, "no puzzle unlocks today ({year}-{month:02}-{day:02} US-Eastern), pass a day number")
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+// 2015 to 2024 had 25 days, from 2025 on there are 12
+pub fn days_in_year(year: u16) -> Result<u8, CalendarError> {
+    match year {
+        y if y < FIRST_YEAR => Err(CalendarError::NoSuchYear(year)),
+        y if y < 2025       => Ok(25),
+        _                   => Ok(12)
+    }
+}
+
+// the instant a puzzle unlocks, as seconds since the unix epoch
+pub fn unlock_time(year: u16, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as u32) * SECS_PER_DAY + UNLOCK_OFFSET_SECS
+}
+
+// the puzzle that unlocked most recently on this instant's US-Eastern date, if there is one
+pub fn today(now: SystemTime) -> Result<PuzzleDay, CalendarError> {
+    let eastern = unix_secs(now) - UNLOCK_OFFSET_SECS;
+    let (year, month, day) = civil_from_days(eastern.div_euclid(SECS_PER_DAY));
+
+    let no_puzzle = CalendarError::NoPuzzleToday { date: (year, month, day) };
+
+    let Ok(year) = u16::try_from(year) else { return Err(no_puzzle) };
+
+    match days_in_year(year) {
+        Ok(days) if month == 12 && day <= days as u32 => Ok(PuzzleDay { year, day: day as u8 }),
+        _ => Err(no_puzzle)
+    }
+}
+
+// make sure a puzzle exists and is unlocked at this instant
+pub fn check(year: u16, day: u8, now: SystemTime) -> Result<PuzzleDay, CalendarError> {
+    let days = days_in_year(year)?;
+
+    if day == 0 || day > days {
+        return Err(CalendarError::NoSuchDay { year, day, days })
+    }
+
+    let remaining = unlock_time(year, day) - unix_secs(now);
+
+    if remaining > 0 {
+        return Err(CalendarError::NotUnlocked {
+            year,
+            day,
+            unlocks_in: Duration::from_secs(remaining as u64)
+        })
+    }
+
+    Ok(PuzzleDay { year, day })
+}
+
+fn unix_secs(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after)   =>  after.as_secs() as i64,
+        Err(before) => -(before.duration().as_secs() as i64)
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60);
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{secs}s"),
+        (0, 0, m) => format!("{m}m {}s", secs % 60),
+        (0, h, m) => format!("{h}h {m}m"),
+        (d, h, _) => format!("{d}d {h}h")
+    }
+}
+
+
+/* Date conversions */
+
+// Howard Hinnant's algorithms, see https://howardhinnant.github.io/date_algorithms.html
+
+// days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era  = year.div_euclid(400);
+    let yoe  = year - era * 400;
+    let mp   = (month as i64 + 9) % 12;
+    let doy  = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe  = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+// the inverse of days_from_civil
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era  = days.div_euclid(146097);
+    let doe  = days - era * 146097;
+    let yoe  = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy  = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp   = (5 * doy + 2) / 153;
+    let day  = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month= if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // an instant given as a UTC date and time
+    fn utc(year: i64, month: u32, day: u32, hour: i64, minute: i64) -> SystemTime {
+        let secs = days_from_civil(year, month, day) * SECS_PER_DAY + hour * 3600 + minute * 60;
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    }
+
+    #[test]
+    fn test_days_in_year() {
+        assert_eq!(Err(CalendarError::NoSuchYear(2014)), days_in_year(2014));
+        assert_eq!(Ok(25), days_in_year(2015));
+        assert_eq!(Ok(25), days_in_year(2024));
+        assert_eq!(Ok(12), days_in_year(2025));
+    }
+
+    #[test]
+    fn test_civil_round_trip() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!((2024, 2, 29), civil_from_days(days_from_civil(2024, 2, 29)));
+        assert_eq!((2025, 12, 31), civil_from_days(days_from_civil(2025, 12, 31)));
+        assert_eq!((1969, 12, 31), civil_from_days(-1));
+    }
+
+    #[test]
+    fn test_today_unlocks_at_midnight_eastern() {
+        // 04:59 UTC on Dec 5 is still Dec 4 in New York
+        assert_eq!(Ok(PuzzleDay { year: 2025, day: 4 }), today(utc(2025, 12, 5, 4, 59)));
+        assert_eq!(Ok(PuzzleDay { year: 2025, day: 5 }), today(utc(2025, 12, 5, 5, 0)));
+
+        // late on Dec 4 in UTC-8 is already Dec 5 in UTC, but that doesn't matter either
+        assert_eq!(Ok(PuzzleDay { year: 2025, day: 4 }), today(utc(2025, 12, 5, 3, 0)));
+    }
+
+    #[test]
+    fn test_today_outside_the_event() {
+        assert!(matches!(today(utc(2025, 11, 30, 12, 0)), Err(CalendarError::NoPuzzleToday { .. })));
+        assert!(matches!(today(utc(2025, 12, 13, 12, 0)), Err(CalendarError::NoPuzzleToday { .. })));
+
+        // but day 13 was fine back when there were 25 days
+        assert_eq!(Ok(PuzzleDay { year: 2024, day: 13 }), today(utc(2024, 12, 13, 12, 0)));
+
+        // midnight Jan 1 UTC is still New Year's Eve in New York
+        assert!(matches!(today(utc(2026, 1, 1, 2, 0)),
+                         Err(CalendarError::NoPuzzleToday { date: (2025, 12, 31) })));
+    }
+
+    #[test]
+    fn test_check() {
+        let now = utc(2025, 12, 5, 12, 0);
+
+        assert_eq!(Ok(PuzzleDay { year: 2025, day: 5 }), check(2025, 5, now));
+        assert_eq!(Ok(PuzzleDay { year: 2023, day: 25 }), check(2023, 25, now));
+
+        assert_eq!(Err(CalendarError::NoSuchDay { year: 2025, day: 13, days: 12 }), check(2025, 13, now));
+        assert_eq!(Err(CalendarError::NoSuchDay { year: 2025, day: 0, days: 12 }), check(2025, 0, now));
+
+        assert_eq!(Err(CalendarError::NotUnlocked { year: 2025, day: 6, unlocks_in: Duration::from_secs(17 * 3600) }),
+                   check(2025, 6, now));
+    }
+
+    #[test]
+    fn test_not_unlocked_message() {
+        let error = check(2025, 6, utc(2025, 12, 6, 4, 58)).unwrap_err();
+        assert_eq!("2025 day 6 isn't unlocked yet, it unlocks at midnight US-Eastern in 2m 0s", error.to_string());
+    }
+}
@@ -0,0 +1,108 @@
+// a thin HTTP client for adventofcode.com. the base URL can be pointed somewhere else with
+// AOC_BASE_URL, which is how the integration tests talk to their stand-in server
+
+use std::fmt;
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::session::{self, Session};
+
+const DEFAULT_BASE_URL  : &str = "https://adventofcode.com";
+const DEFAULT_USER_AGENT: &str = "github.com/jasonincanada/aoc-2025 by jasonincanada";
+
+pub struct Client {
+    agent   : Agent,
+    base_url: String,
+    session : Session
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body  : String
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    // we never got an HTTP response at all
+    Transport { url: String, message: String }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Transport { url, message } =>
+                write!(f, "request to {url} failed: {}", session::redact(message))
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl Client {
+    pub fn new(session: Session, user_agent: Option<String>) -> Self {
+        let user_agent = user_agent
+            .or_else(|| std::env::var("AOC_USER_AGENT").ok())
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string());
+
+        let base_url = std::env::var("AOC_BASE_URL")
+            .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        // we want to see 4xx/5xx responses ourselves so we can say something useful about them
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(user_agent)
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, ClientError> {
+        let url = self.url(path);
+
+        let result = self.agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session.token()))
+            .call();
+
+        read_response(&url, result)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, ClientError> {
+        let url = self.url(path);
+
+        let result = self.agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session.token()))
+            .send_form(form.iter().copied());
+
+        read_response(&url, result)
+    }
+}
+
+fn read_response(url: &str, result: Result<ureq::http::Response<ureq::Body>, ureq::Error>)
+    -> Result<Response, ClientError>
+{
+    let transport = |error: ureq::Error| ClientError::Transport {
+        url    : url.to_string(),
+        message: error.to_string()
+    };
+
+    let mut response = result.map_err(transport)?;
+    let status = response.status().as_u16();
+    let body   = response.body_mut()
+                         .read_to_string()
+                         .map_err(transport)?;
+
+    Ok(Response { status, body })
+}
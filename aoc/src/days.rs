@@ -0,0 +1,96 @@
+// the table of days the runner knows about. each entry is a day crate's Solution impl,
+// erased down to a plain function pointer so they can all live in one list
+
+use common::{Answer, Solution};
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::render::Palette;
+
+use crate::bench::{self, Settings, Timings};
+
+// one answer per part asked for
+pub type Answers = Vec<Option<String>>;
+
+// a simulation's frames and the colours to draw them in
+pub type Frames = (Vec<Grid<u8>>, Palette);
+
+pub struct Day {
+    pub number: u8,
+
+    // the sample file for each part. most days share one, day 11 has a second for part 2
+    pub samples: [&'static str; 2],
+
+    // parse the text and solve the given parts, returning the answers in the same order.
+    // a part that hasn't been solved yet comes back as None
+    pub solve: fn(text: &str, parts: &[u8]) -> Result<Answers, ParseError>,
+
+    // time parse, part 1 and part 2 separately against the text
+    pub bench: fn(text: &str, settings: &Settings) -> Result<Timings, ParseError>,
+
+    // the frames of the day's simulation for the text, none if it doesn't have one
+    pub animate: fn(text: &str) -> Result<Frames, ParseError>
+}
+
+fn solve<S: Solution>(text: &str, parts: &[u8]) -> Result<Answers, ParseError> {
+    let input = S::parse(text)?;
+
+    let answers = match parts {
+        [1, 2] => {
+            let (part1, part2) = S::parts(&input);
+            vec![render(part1), render(part2)]
+        },
+
+        _ => parts.iter()
+                  .map(|part| match part {
+                      1 => render(S::part1(&input)),
+                      _ => render(S::part2(&input))
+                  })
+                  .collect()
+    };
+
+    Ok(answers)
+}
+
+fn animate<S: Solution>(text: &str) -> Result<Frames, ParseError> {
+    Ok((S::frames(&S::parse(text)?), S::palette()))
+}
+
+fn render(answer: impl Answer) -> Option<String> {
+    answer.is_solved()
+          .then(|| answer.to_string())
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        day!($solution, ["sample.txt", "sample.txt"])
+    };
+
+    ($solution:ty, $samples:expr) => {
+        Day {
+            number : <$solution>::DAY,
+            samples: $samples,
+            solve  : solve::<$solution>,
+            bench  : bench::bench::<$solution>,
+            animate: animate::<$solution>
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day09::Day09),
+    day!(day10::Day10),
+    day!(day11::Day11, ["sample.txt", "sample2.txt"]),
+    day!(day12::Day12),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+}
@@ -0,0 +1,115 @@
+// sanity checks for the local setup: is there a usable session token, and has one been
+// left lying around somewhere in the working tree
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::session::{self, Session};
+
+// directories that are never committed, or are too big to be worth reading, for when there's no
+// git to ask which files are
+const SKIP_DIRS: &[&str] = &[".git", "target"];
+
+pub struct Finding {
+    pub path: PathBuf,
+    pub line: usize
+}
+
+// print the results of every check, returning false if any of them failed
+pub fn run(root: &Path) -> bool {
+    let mut healthy = true;
+
+    match Session::load() {
+        Ok(session) => println!("session: ok, loaded from {}", session.source),
+        Err(error)  => {
+            println!("session: {}", session::redact(&error.to_string()));
+            healthy = false;
+        }
+    }
+
+    let findings = scan(root);
+
+    if findings.is_empty() {
+        println!("tree:    ok, no session tokens found");
+    }
+
+    for finding in findings {
+        println!("tree:    {}:{} looks like it contains a session token",
+                 finding.path.display(), finding.line);
+        healthy = false;
+    }
+
+    healthy
+}
+
+// look for lines that contain something shaped like a session token, in the files that are
+// committed or could be (ignored files like the inputs never leave this machine)
+pub fn scan(root: &Path) -> Vec<Finding> {
+    let files = committable(root).unwrap_or_else(|| every_file(root));
+
+    let mut findings = vec![];
+
+    for path in files {
+        // binary files can't hold a pasted cookie in any form we'd recognise
+        let Ok(text) = fs::read_to_string(root.join(&path)) else { continue };
+
+        for (i, line) in text.lines().enumerate() {
+            if !session::find_tokens(line).is_empty() {
+                findings.push(Finding { path: path.clone(), line: i + 1 });
+            }
+        }
+    }
+
+    findings
+}
+
+// the tracked files and the untracked ones that aren't ignored, or None outside a git repo
+fn committable(root: &Path) -> Option<Vec<PathBuf>> {
+    let output = Command::new("git")
+        .arg("-C").arg(root)
+        .args(["ls-files", "-z", "--cached", "--others", "--exclude-standard"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None
+    }
+
+    let mut files: Vec<PathBuf> = String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect();
+
+    files.sort();
+    files.dedup();
+    Some(files)
+}
+
+// everything under the root but the skipped directories, relative to it
+fn every_file(root: &Path) -> Vec<PathBuf> {
+    let mut files   = vec![];
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+
+        for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+            let name = path.file_name()
+                           .and_then(|name| name.to_str())
+                           .unwrap_or_default();
+
+            if path.is_dir() {
+                if !SKIP_DIRS.contains(&name) {
+                    pending.push(path);
+                }
+            } else {
+                files.push(path.strip_prefix(root).unwrap_or(&path).to_path_buf());
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
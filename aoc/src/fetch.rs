@@ -0,0 +1,122 @@
+// download a day's puzzle input once and cache it as dayNN/input.txt (inputs/YYYY/dayNN/input.txt
+// for other years), and the puzzle page itself
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::calendar;
+use crate::client::{Client, ClientError};
+
+#[derive(Debug)]
+pub enum FetchError {
+    AlreadyCached(PathBuf),
+    Client(ClientError),
+    Http { url: String, status: u16, body: String },
+    Io { path: PathBuf, error: std::io::Error }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::AlreadyCached(path) =>
+                write!(f, "{} already exists, delete it first if you really want to download it again", path.display()),
+
+            FetchError::Client(error) => write!(f, "{error}"),
+
+            FetchError::Http { url, status, body } => {
+                write!(f, "{url} returned HTTP {status}")?;
+
+                // adventofcode.com answers with a 400 when the session cookie is missing or
+                // stale, and a 404 for days that aren't unlocked yet
+                match status {
+                    400 => write!(f, " (is the session token expired?)")?,
+                    404 => write!(f, " (is the puzzle unlocked yet?)")?,
+                    _   => ()
+                }
+
+                match body.lines().next() {
+                    Some(line) if !line.trim().is_empty() => write!(f, ": {}", line.trim()),
+                    _ => Ok(())
+                }
+            },
+
+            FetchError::Io { path, error } =>
+                write!(f, "couldn't write {}: {error}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ClientError> for FetchError {
+    fn from(error: ClientError) -> Self {
+        FetchError::Client(error)
+    }
+}
+
+// where a day's input gets cached, in the day's crate
+pub fn input_path(day: u8) -> PathBuf {
+    common::day_dir(day).join("input.txt")
+}
+
+// the day crates are this year's puzzles, so other years' inputs go off to one side where they
+// can't be mistaken for (or overwrite) this year's
+pub fn year_input_path(year: u16, day: u8) -> PathBuf {
+    match year {
+        calendar::YEAR => input_path(day),
+        _              => common::root().join(format!("inputs/{year}/day{day:02}/input.txt"))
+    }
+}
+
+pub fn fetch_input(client: &Client, year: u16, day: u8) -> Result<PathBuf, FetchError> {
+    let path = year_input_path(year, day);
+
+    // check before we hit the server, inputs never change so there's no reason to ask twice
+    if path.exists() {
+        return Err(FetchError::AlreadyCached(path))
+    }
+
+    let url_path = format!("/{year}/day/{day}/input");
+    let response = client.get(&url_path)?;
+
+    if response.status != 200 {
+        return Err(FetchError::Http {
+            url   : client.url(&url_path),
+            status: response.status,
+            body  : response.body
+        })
+    }
+
+    let io_error = |error| FetchError::Io { path: path.clone(), error };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+
+    // create_new so we can never clobber an input that showed up while we were downloading
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, response.body.as_bytes()))
+        .map_err(io_error)?;
+
+    Ok(path)
+}
+
+// the puzzle's page, for scraping the sample out of. not cached, it changes once part 1 is solved
+pub fn fetch_puzzle(client: &Client, year: u16, day: u8) -> Result<String, FetchError> {
+    let url_path = format!("/{year}/day/{day}");
+    let response = client.get(&url_path)?;
+
+    if response.status != 200 {
+        return Err(FetchError::Http {
+            url   : client.url(&url_path),
+            status: response.status,
+            body  : response.body
+        })
+    }
+
+    Ok(response.body)
+}
@@ -0,0 +1,838 @@
+// one runner for every day in the workspace
+//
+//   $ aoc run 5
+//   $ aoc run all
+//   $ aoc run 11 --part 2
+//   $ aoc run 9 --input other.txt --input -
+//   $ aoc bench all
+//   $ aoc animate 4 --gif rolls.gif
+//   $ aoc new 8 --fetch
+//   $ aoc fetch 5
+//   $ aoc submit 5 1
+//   $ aoc guess 1 2 7937 too-high
+//   $ aoc guesses 1
+//   $ aoc doctor
+//   $ aoc readme
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use clap::{Parser, Subcommand};
+use common::answers::{self, Entry, Puzzle, Registry, Verdict};
+use common::guesses::{self, Feedback, Guess, GuessLog};
+use common::render::{self, Still};
+
+mod bench;
+mod calendar;
+mod client;
+mod days;
+mod doctor;
+mod fetch;
+mod readme;
+mod scaffold;
+mod session;
+mod submit;
+
+use bench::{Human, Settings};
+use calendar::{CalendarError, PuzzleDay};
+use client::Client;
+use days::Day;
+use session::Session;
+use submit::Outcome;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day (or all of them) against the sample and the puzzle input
+    Run {
+        /// The day number, or "all"
+        day: Days,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Solve against these files instead of the day's sample and input, "-" for stdin.
+        /// Can be given more than once
+        #[arg(long = "input", short, value_name = "PATH")]
+        inputs: Vec<PathBuf>
+    },
+
+    /// Time each day's parse, part 1 and part 2 separately, and compare with the last run
+    ///
+    /// Every run is appended to bench-history.tsv at the workspace root, and the next run
+    /// flags any stage that got noticeably slower.
+    Bench {
+        /// The day number, or "all"
+        day: Days,
+
+        /// Time against the sample instead of the puzzle input
+        #[arg(long)]
+        sample: bool,
+
+        /// Time against this file instead, like a stress input
+        #[arg(long, short, value_name = "PATH", conflicts_with = "sample")]
+        input: Option<PathBuf>,
+
+        /// How many timed runs of each stage
+        #[arg(long, default_value_t = 50)]
+        samples: usize,
+
+        /// How many untimed runs of each stage first
+        #[arg(long, default_value_t = 5)]
+        warmup: usize,
+
+        /// Give up sampling a stage after this many seconds (it still gets 3 samples)
+        #[arg(long, default_value_t = 5.0)]
+        budget: f64
+    },
+
+    /// Watch a day's simulation: play it in the terminal, or save it as a GIF or numbered stills
+    ///
+    /// Only days that produce frames can be animated (4 and 7). Each tile is a square of
+    /// --scale pixels, coloured by the day's palette.
+    Animate {
+        day: u8,
+
+        /// Animate the sample instead of the puzzle input
+        #[arg(long)]
+        sample: bool,
+
+        /// Animate this file instead
+        #[arg(long, short, value_name = "PATH", conflicts_with = "sample")]
+        input: Option<PathBuf>,
+
+        /// Save an animated GIF here instead of playing in the terminal
+        #[arg(long, value_name = "PATH")]
+        gif: Option<PathBuf>,
+
+        /// Save each frame as a PNG in this directory
+        #[arg(long, value_name = "DIR", conflicts_with = "gif")]
+        png: Option<PathBuf>,
+
+        /// Save each frame as a PPM in this directory
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["gif", "png"])]
+        ppm: Option<PathBuf>,
+
+        /// Pixels per tile in saved images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+
+        /// Milliseconds per frame
+        #[arg(long, default_value_t = 100)]
+        delay: u64
+    },
+
+    /// Start a new day: its crate from the template, an empty sample and empty answer slots
+    ///
+    /// The crate is added to the workspace, the runner and the answer registry. The template
+    /// comes from template/ at the workspace root if there is one, or from --template.
+    New {
+        day: u8,
+
+        /// Also download the input and pull the sample out of the puzzle text
+        #[arg(long)]
+        fetch: bool,
+
+        /// A directory to take the template from instead
+        #[arg(long)]
+        template: Option<PathBuf>,
+
+        /// User-Agent header to send, defaults to AOC_USER_AGENT or one naming this repo
+        #[arg(long)]
+        user_agent: Option<String>
+    },
+
+    /// Download a day's puzzle input into dayNN/input.txt
+    ///
+    /// The session token comes from AOC_SESSION, or from aoc/session in your config
+    /// directory (override the file with AOC_SESSION_FILE). The file must be chmod 600.
+    Fetch {
+        /// The day number, defaults to today's puzzle during the event
+        day: Option<u8>,
+
+        /// The puzzle year, defaults to 2025. Other years are saved under inputs/YEAR/
+        #[arg(long, requires = "day")]
+        year: Option<u16>,
+
+        /// User-Agent header to send, defaults to AOC_USER_AGENT or one naming this repo
+        #[arg(long)]
+        user_agent: Option<String>
+    },
+
+    /// Solve a part against the real input and submit the answer
+    ///
+    /// The answer is checked against the registry and the guess log first, and the result is
+    /// recorded in both.
+    Submit {
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Submit even if the guess log says the answer can't be right
+        #[arg(long)]
+        force: bool,
+
+        /// Sleep through the cooldown from the last submission instead of giving up
+        #[arg(long)]
+        wait: bool,
+
+        /// User-Agent header to send, defaults to AOC_USER_AGENT or one naming this repo
+        #[arg(long)]
+        user_agent: Option<String>
+    },
+
+    /// Record an answer submitted on the website for the real input, and what it said
+    Guess {
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: String,
+
+        /// correct, too-high, too-low or wrong
+        feedback: Feedback
+    },
+
+    /// Show the guesses made for a day and the bounds they put on the answers
+    Guesses {
+        day: u8
+    },
+
+    /// Check the session token setup and scan the working tree for leaked tokens
+    Doctor,
+
+    /// Rebuild the progress tables in README.md from the registry
+    ///
+    /// Only the section between the BEGIN/END aoc readme markers is rewritten. Titles and
+    /// descriptions come from the [[puzzle]] entries in answers/2025.toml, stars from its confirmed
+    /// answers, and the Past Years table from answers/past-years.toml.
+    Readme {
+        /// Don't write anything, just fail if README.md is out of date
+        #[arg(long)]
+        check: bool
+    }
+}
+
+#[derive(Clone)]
+enum Days {
+    All,
+    One(u8)
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Days::All)
+        }
+
+        s.parse()
+         .map(Days::One)
+         .map_err(|_| format!("expected a day number or \"all\", got \"{s}\""))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", session::redact(&error.to_string()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run { day, part, inputs } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None       => vec![1, 2]
+            };
+
+            if !inputs.is_empty() && matches!(day, Days::All) {
+                return Err("--input needs a single day".into())
+            }
+
+            let selected = select(day)?;
+            let known  = Known::load()?;
+            let mut ok = true;
+
+            for (i, day) in selected.into_iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+
+                ok &= match inputs.is_empty() {
+                    true  => run_day(day, &parts, &known),
+                    false => run_inputs(day, &parts, &known, &inputs)?
+                };
+            }
+
+            if !ok {
+                return Err("some inputs didn't parse or answers didn't match the registry".into())
+            }
+        },
+
+        Command::Bench { day, sample, input, samples, warmup, budget } => {
+            let settings = Settings {
+                warmup,
+                samples: samples.max(1),
+                budget : Duration::try_from_secs_f64(budget)
+                             .map_err(|_| format!("invalid budget {budget}"))?
+            };
+
+            if input.is_some() && matches!(day, Days::All) {
+                return Err("--input needs a single day".into())
+            }
+
+            let selected = select(day)?;
+            let file     = if sample { "sample.txt" } else { "input.txt" };
+
+            for (i, day) in selected.into_iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+
+                let path = input.clone().unwrap_or_else(|| common::day_dir(day.number).join(file));
+                bench_day(day, &path, &settings)?;
+            }
+        },
+
+        Command::Animate { day, sample, input, gif, png, ppm, scale, delay } => {
+            let solver = days::find(day).ok_or(format!("day {day} is not in the workspace"))?;
+            let file   = if sample { "sample.txt" } else { "input.txt" };
+            let path   = input.unwrap_or_else(|| common::day_dir(day).join(file));
+
+            let output = match (gif, png, ppm) {
+                (Some(path), _, _) => Output::Gif(path),
+                (_, Some(dir), _)  => Output::Stills(Still::Png, dir),
+                (_, _, Some(dir))  => Output::Stills(Still::Ppm, dir),
+                _                  => Output::Terminal
+            };
+
+            animate_day(solver, &path, output, scale.max(1), Duration::from_millis(delay))?
+        },
+
+        Command::New { day, fetch, template, user_agent } =>
+            new_day(day, fetch, template, user_agent)?,
+
+        Command::Fetch { day, year, user_agent } => {
+            let puzzle  = puzzle_day(day, year)?;
+            let session = Session::load()?;
+            let client  = Client::new(session, user_agent);
+            let path    = fetch::fetch_input(&client, puzzle.year, puzzle.day)?;
+
+            println!("Saved {}", path.display());
+        },
+
+        Command::Submit { day, part, force, wait, user_agent } =>
+            submit_answer(day, part, force, wait, user_agent)?,
+
+        Command::Guess { day, part, answer, feedback } => {
+            let mut known = Known::load()?;
+
+            known.record(day, part, answer, feedback)?;
+
+            println!("Day {day} part {part}: {}", known.guesses.bounds(day, part));
+        },
+
+        Command::Guesses { day } => {
+            let known = Known::load()?;
+
+            for part in 1..=2 {
+                println!("Part {part}: {}", known.guesses.bounds(day, part));
+
+                for guess in known.guesses.for_part(day, part) {
+                    println!("  {:<20} {}", guess.answer, guess.feedback);
+                }
+            }
+        },
+
+        Command::Doctor => {
+            if !doctor::run(Path::new(".")) {
+                return Err("doctor found problems".into())
+            }
+        },
+
+        Command::Readme { check } => update_readme(check)?
+    }
+
+    Ok(())
+}
+
+fn select(days: Days) -> Result<Vec<&'static Day>, String> {
+    match days {
+        Days::All => Ok(days::DAYS.iter().collect()),
+        Days::One(number) => match days::find(number) {
+            Some(day) => Ok(vec![day]),
+            None      => Err(format!("day {number} is not in the workspace"))
+        }
+    }
+}
+
+// an explicit day (in 2025 unless another year is given) must already be unlocked, and no
+// day at all means today's puzzle
+fn puzzle_day(day: Option<u8>, year: Option<u16>) -> Result<PuzzleDay, CalendarError> {
+    let now = SystemTime::now();
+
+    match day {
+        Some(day) => calendar::check(year.unwrap_or(calendar::YEAR), day, now),
+        None      => calendar::today(now)
+    }
+}
+
+// what we already know about this year's answers
+struct Known {
+    registry: Registry,
+    guesses : GuessLog
+}
+
+impl Known {
+    fn load() -> Result<Known, Box<dyn Error>> {
+        Ok(Known {
+            registry: Registry::load(&answers::path(calendar::YEAR))?,
+            guesses : GuessLog::load(&guesses::path(calendar::YEAR))?
+        })
+    }
+
+    // log a guess at the real input. a correct one is a confirmed answer for the registry too
+    fn record(&mut self, day: u8, part: u8, answer: String, feedback: Feedback) -> std::io::Result<()> {
+        self.guesses.record(Guess { day, part, answer: answer.clone(), feedback });
+        self.guesses.save(&guesses::path(calendar::YEAR))?;
+
+        if feedback == Feedback::Correct {
+            self.registry.set(Entry { day, part, input: "input".to_string(), answer, confirmed: true });
+            self.registry.save(&answers::path(calendar::YEAR))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn new_day(day: u8, fetch: bool, template: Option<PathBuf>, user_agent: Option<String>)
+    -> Result<(), Box<dyn Error>>
+{
+    let days = calendar::days_in_year(calendar::YEAR)?;
+
+    if day == 0 || day > days {
+        return Err(CalendarError::NoSuchDay { year: calendar::YEAR, day, days }.into())
+    }
+
+    // only check the calendar up front if we're going to need the puzzle, it's fine to set up
+    // tomorrow's crate tonight
+    if fetch {
+        calendar::check(calendar::YEAR, day, SystemTime::now())?;
+    }
+
+    let root     = common::root();
+    let template = template.or_else(|| Some(scaffold::template_dir(&root)).filter(|dir| dir.is_dir()));
+    let files    = scaffold::load_template(template.as_deref())?;
+    let dir      = scaffold::create_day(&root, calendar::YEAR, day, &files)?;
+
+    println!("Created {}", dir.display());
+
+    if !fetch {
+        return Ok(())
+    }
+
+    let client = Client::new(Session::load()?, user_agent);
+
+    let path = fetch::fetch_input(&client, calendar::YEAR, day)?;
+    println!("Saved {}", path.display());
+
+    let page = fetch::fetch_puzzle(&client, calendar::YEAR, day)?;
+
+    // the title for the README. the description is up to us
+    if let Some(title) = scaffold::extract_title(&page) {
+        let path = answers::path(calendar::YEAR);
+        let mut registry = Registry::load(&path)?;
+
+        if registry.puzzle(day).is_none() {
+            registry.set_puzzle(Puzzle { day, title, description: String::new() });
+            registry.save(&path)?;
+        }
+    }
+
+    match scaffold::extract_sample(&page) {
+        Some(sample) => {
+            let path = dir.join("sample.txt");
+            fs::write(&path, sample)?;
+            println!("Saved {}", path.display());
+        },
+        None => println!("Couldn't find a sample in the puzzle text, {} is still empty", dir.join("sample.txt").display())
+    }
+
+    Ok(())
+}
+
+enum Output {
+    Terminal,
+    Gif(PathBuf),
+    Stills(Still, PathBuf)
+}
+
+fn animate_day(day: &Day, path: &Path, output: Output, scale: usize, delay: Duration) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("couldn't read {}: {error}", shown(path)))?;
+
+    let (frames, palette) = (day.animate)(&text).map_err(|error| error.in_file(shown(path)))?;
+
+    if frames.is_empty() {
+        return Err(format!("day {} has no simulation to animate", day.number).into())
+    }
+
+    match output {
+        Output::Terminal => render::play(&frames, &palette, delay, &mut io::stdout().lock())?,
+
+        Output::Gif(path) => {
+            fs::write(&path, render::gif(&frames, &palette, scale, delay))
+                .map_err(|error| format!("couldn't write {}: {error}", shown(&path)))?;
+
+            println!("Saved {} frames to {}", frames.len(), shown(&path));
+        },
+
+        Output::Stills(format, dir) => {
+            render::write_stills(&frames, &palette, scale, format, &dir)
+                .map_err(|error| format!("couldn't write to {}: {error}", shown(&dir)))?;
+
+            println!("Saved {} frames to {}", frames.len(), shown(&dir));
+        }
+    }
+
+    Ok(())
+}
+
+fn update_readme(check: bool) -> Result<(), Box<dyn Error>> {
+    let root     = common::root();
+    let path     = root.join("README.md");
+    let registry = Registry::load(&answers::path(calendar::YEAR))?;
+    let past     = readme::load_past_years(&readme::past_years_path(&root))?;
+
+    let text = fs::read_to_string(&path)
+        .map_err(|error| format!("couldn't read {}: {error}", path.display()))?;
+
+    let section = readme::render(&root, &registry, &past);
+    let updated = readme::splice(&text, &section)
+        .ok_or(readme::ReadmeError::NoMarkers(path.clone()))?;
+
+    if updated == text {
+        println!("{} is up to date", path.display());
+        return Ok(())
+    }
+
+    if check {
+        return Err(format!("{} is out of date, run aoc readme", path.display()).into())
+    }
+
+    fs::write(&path, updated)?;
+    println!("Updated {}", path.display());
+
+    Ok(())
+}
+
+fn submit_answer(day: u8, part: u8, force: bool, wait: bool, user_agent: Option<String>)
+    -> Result<(), Box<dyn Error>>
+{
+    let puzzle = calendar::check(calendar::YEAR, day, SystemTime::now())?;
+    let solver = days::find(day).ok_or(format!("day {day} is not in the workspace"))?;
+
+    let path = fetch::input_path(day);
+    let text = fs::read_to_string(&path)
+        .map_err(|error| format!("couldn't read {}: {error}", path.display()))?;
+
+    let answer = (solver.solve)(&text, &[part])
+        .map_err(|error| error.in_file(shown(&path)))?
+        .remove(0)
+        .ok_or(format!("day {day} part {part} isn't solved yet"))?;
+
+    let mut known = Known::load()?;
+
+    // everything we can check locally, before spending a submission on it
+    if let Some(entry) = known.registry.get(day, part, "input")
+        && entry.confirmed
+    {
+        return Err(format!("day {day} part {part} is already solved, the answer is {}", entry.answer).into())
+    }
+
+    if !force && let Some(problem) = known.guesses.assess(day, part, &answer) {
+        return Err(format!("not submitting {answer}: {problem} (use --force to submit anyway)").into())
+    }
+
+    if let Some(remaining) = submit::cooldown_remaining(SystemTime::now()) {
+        if !wait {
+            return Err(format!("the last answer was too recent, wait {}s or use --wait", remaining.as_secs()).into())
+        }
+
+        println!("Waiting {}s before submitting", remaining.as_secs());
+        std::thread::sleep(remaining);
+    }
+
+    let session = Session::load()?;
+    let client  = Client::new(session, user_agent);
+
+    println!("Submitting {answer} for day {day} part {part}");
+
+    let mut reply = submit::submit(&client, puzzle.year, day, part, &answer)?;
+
+    // the cooldown file can be out of date, so the site may still tell us to wait
+    while wait
+        && reply.outcome == Outcome::TooRecent
+        && let Some(remaining) = reply.wait
+    {
+        submit::start_cooldown(SystemTime::now(), remaining)?;
+
+        println!("Too soon, waiting {}s before submitting again", remaining.as_secs());
+        std::thread::sleep(remaining);
+
+        reply = submit::submit(&client, puzzle.year, day, part, &answer)?;
+    }
+
+    if let Some(wait) = reply.wait {
+        submit::start_cooldown(SystemTime::now(), wait)?;
+    }
+
+    let feedback = match reply.outcome {
+        Outcome::Correct => Feedback::Correct,
+        Outcome::TooHigh => Feedback::TooHigh,
+        Outcome::TooLow  => Feedback::TooLow,
+        Outcome::Wrong   => Feedback::Wrong,
+
+        Outcome::AlreadySolved => {
+            println!("Already solved, the site won't say whether {answer} is right");
+            return Ok(())
+        },
+
+        Outcome::TooRecent => {
+            let secs = reply.wait.unwrap_or_default().as_secs();
+            return Err(format!("the last answer was too recent, wait {secs}s or use --wait").into())
+        },
+
+        Outcome::Unrecognised(text) =>
+            return Err(format!("couldn't make sense of the response: {text}").into())
+    };
+
+    known.record(day, part, answer.clone(), feedback)?;
+
+    if feedback != Feedback::Correct {
+        return Err(format!("{answer} is {feedback}, {}", known.guesses.bounds(day, part)).into())
+    }
+
+    println!("{answer} is correct!");
+
+    Ok(())
+}
+
+// solve a day's parts against its samples and input, checking each answer against the registry
+// and the guess log. returns false if an input doesn't parse or any answer contradicts a confirmed one
+fn run_day(day: &Day, parts: &[u8], known: &Known) -> bool {
+    let dir = common::day_dir(day.number);
+
+    println!("Day {}", day.number);
+
+    // group the parts by sample file so a shared sample only gets parsed once
+    let mut samples: Vec<(&str, Vec<u8>)> = vec![];
+
+    for &part in parts {
+        let file = day.samples[part as usize - 1];
+
+        match samples.last_mut() {
+            Some((last, parts)) if *last == file => parts.push(part),
+            _ => samples.push((file, vec![part]))
+        }
+    }
+
+    let mut ok = true;
+
+    // the registry knows inputs by file name, "sample" for sample.txt
+    for (file, parts) in samples {
+        let path = dir.join(file);
+        ok &= print_answers(day, known, "Sample part", &path, registry_name(day, &path), &parts);
+    }
+
+    let path = dir.join("input.txt");
+    ok & print_answers(day, known, "Part", &path, Some("input"), parts)
+}
+
+// solve a day against the files given on the command line. stdin is read once, however many times
+// it's asked for
+fn run_inputs(day: &Day, parts: &[u8], known: &Known, inputs: &[PathBuf]) -> io::Result<bool> {
+    let mut stdin: Option<String> = None;
+    let mut ok = true;
+
+    println!("Day {}", day.number);
+
+    for path in inputs {
+        if path != Path::new("-") {
+            println!("{}", shown(path));
+            ok &= print_answers(day, known, "Part", path, registry_name(day, path), parts);
+            continue
+        }
+
+        if stdin.is_none() {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            stdin = Some(text);
+        }
+
+        println!("(stdin)");
+        ok &= solve_text(day, known, "Part", Path::new("(stdin)"), stdin.as_deref().unwrap(), None, parts);
+    }
+
+    Ok(ok)
+}
+
+// the name the registry knows a file by, if it's one of the day's own files
+fn registry_name<'a>(day: &Day, path: &'a Path) -> Option<&'a str> {
+    let dir = common::day_dir(day.number).canonicalize().ok()?;
+
+    match path.canonicalize().ok()?.parent() == Some(dir.as_path()) {
+        true  => path.file_stem()?.to_str(),
+        false => None
+    }
+}
+
+// a path relative to where we were run from, if it's under there
+fn shown(path: &Path) -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+// don't let a missing input file take down a whole `aoc run all`
+fn print_answers(day: &Day, known: &Known, label: &str, path: &Path, input: Option<&str>, parts: &[u8]) -> bool {
+    let Ok(text) = fs::read_to_string(path) else {
+        for part in parts {
+            let label = format!("{label} {part}:");
+            println!("{label:<15}(missing {})", shown(path));
+        }
+        return true
+    };
+
+    // a new day's sample.txt before anything's been pasted into it
+    if text.trim().is_empty() {
+        for part in parts {
+            let label = format!("{label} {part}:");
+            println!("{label:<15}(empty {})", shown(path));
+        }
+        return true
+    }
+
+    solve_text(day, known, label, path, &text, input, parts)
+}
+
+// solve and print each part, with what the registry and the guess log say about the answer when
+// the input is one they know
+fn solve_text(day: &Day, known: &Known, label: &str, path: &Path, text: &str, input: Option<&str>, parts: &[u8])
+    -> bool
+{
+    let mut ok = true;
+
+    // the wrong file or a truncated download, rather than anything about the answers
+    let answers = match (day.solve)(text, parts) {
+        Ok(answers) => answers,
+        Err(error)  => {
+            println!("{}", error.in_file(shown(path)));
+            return false
+        }
+    };
+
+    for (&part, answer) in parts.iter().zip(answers) {
+        let label = format!("{label} {part}:");
+
+        let Some(answer) = answer else {
+            println!("{label:<15}unsolved");
+            continue
+        };
+
+        let Some(input) = input else {
+            println!("{label:<15}{answer}");
+            continue
+        };
+
+        let entry = known.registry.get(day.number, part, input);
+
+        let mut note = match answers::judge(entry, &answer) {
+            Verdict::Correct                             => "(correct)".to_string(),
+            Verdict::MatchesUnconfirmed                  => "(matches the unconfirmed answer)".to_string(),
+            Verdict::DiffersFromUnconfirmed { recorded } => format!("(the unconfirmed answer is {recorded})"),
+            Verdict::Unknown | Verdict::Unsolved         => String::new(),
+            Verdict::Wrong { expected } => {
+                ok = false;
+                format!("(wrong, expected {expected})")
+            }
+        };
+
+        // only the real input has been guessed at. a confirmed answer speaks for itself
+        if input == "input"
+            && !entry.is_some_and(|entry| entry.confirmed)
+            && let Some(problem) = known.guesses.assess(day.number, part, &answer)
+        {
+            let warning = format!("(don't submit: {problem})");
+
+            note = if note.is_empty() { warning } else { format!("{note} {warning}") };
+        }
+
+        println!("{}", format!("{label:<15}{answer:<16} {note}").trim_end());
+    }
+
+    ok
+}
+
+// time a day against one of its files, print the stats next to the last recorded run of each
+// stage, then record this run as the new baseline
+// the history knows inputs by file name, like the registry, so a stress input's runs are only
+// compared with each other
+fn bench_day(day: &Day, path: &Path, settings: &Settings) -> Result<(), Box<dyn Error>> {
+    println!("Day {}", day.number);
+
+    let Ok(text) = fs::read_to_string(path) else {
+        println!("  (missing {})", shown(path));
+        return Ok(())
+    };
+
+    let input   = path.file_stem().map_or("input".into(), |stem| stem.to_string_lossy());
+    let history = bench::load_history();
+    let timings = (day.bench)(&text, settings).map_err(|error| error.in_file(shown(path)))?;
+
+    println!("  {:<8}{:>10}{:>10}{:>10}{:>10}{:>9}   vs last run", "", "median", "±mad", "min", "max", "samples");
+
+    for (stage, stats) in bench::STAGES.iter().zip(&timings) {
+        let Some(stats) = stats else {
+            println!("  {stage:<8}unsolved");
+            continue
+        };
+
+        let change = bench::baseline(&history, day.number, &input, stage)
+            .map(|baseline| bench::compare(stats, baseline))
+            .unwrap_or_default();
+
+        let line = format!("  {stage:<8}{:>10}{:>10}{:>10}{:>10}{:>9}   {change}",
+                           Human(stats.median), Human(stats.mad), Human(stats.min), Human(stats.max),
+                           stats.samples);
+
+        println!("{}", line.trim_end());
+    }
+
+    Ok(bench::append_history(day.number, &input, &timings)?)
+}
@@ -0,0 +1,185 @@
+// rebuild the README's progress tables from the registry, so the stars only ever show answers
+// adventofcode.com has actually accepted. only the text between the markers is touched
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use common::answers::Registry;
+
+pub const BEGIN: &str = "<!-- BEGIN aoc readme -->";
+pub const END  : &str = "<!-- END aoc readme -->";
+
+#[derive(Debug, Deserialize)]
+pub struct PastYear {
+    pub year    : u16,
+    pub language: String,
+    pub stars   : u32,
+    pub url     : String
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PastYears {
+    #[serde(default, rename = "year")]
+    pub years: Vec<PastYear>
+}
+
+#[derive(Debug)]
+pub enum ReadmeError {
+    Io { path: PathBuf, error: std::io::Error },
+    Toml { path: PathBuf, error: toml::de::Error },
+    NoMarkers(PathBuf)
+}
+
+impl fmt::Display for ReadmeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadmeError::Io { path, error }   => write!(f, "couldn't read {}: {error}", path.display()),
+            ReadmeError::Toml { path, error } => write!(f, "couldn't parse {}: {error}", path.display()),
+            ReadmeError::NoMarkers(path)      =>
+                write!(f, "{} has no section to rewrite, mark one with {BEGIN} and {END}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for ReadmeError {}
+
+// answers/past-years.toml at the root of the workspace
+pub fn past_years_path(root: &Path) -> PathBuf {
+    root.join("answers").join("past-years.toml")
+}
+
+pub fn load_past_years(path: &Path) -> Result<PastYears, ReadmeError> {
+    if !path.exists() {
+        return Ok(PastYears::default())
+    }
+
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| ReadmeError::Io { path: path.to_path_buf(), error })?;
+
+    toml::from_str(&text)
+        .map_err(|error| ReadmeError::Toml { path: path.to_path_buf(), error })
+}
+
+// the whole generated section, newest day first. a day's title links to its source once it has
+// a crate in the workspace
+pub fn render(root: &Path, registry: &Registry, past: &PastYears) -> String {
+    let days: Vec<Vec<String>> = registry.puzzles
+        .iter()
+        .rev()
+        .map(|puzzle| {
+            let source = format!("./day{:02}/src/lib.rs", puzzle.day);
+
+            let title = if root.join(&source).exists() {
+                format!("[{}]({source})", puzzle.title)
+            } else {
+                puzzle.title.clone()
+            };
+
+            let stars = "★".repeat(registry.stars(puzzle.day));
+
+            vec![puzzle.day.to_string(), title, puzzle.description.clone(), format!("{stars:<2}")]
+        })
+        .collect();
+
+    let years: Vec<Vec<String>> = past.years
+        .iter()
+        .map(|year| vec![format!("[{}]({})", year.year, year.url), year.language.clone(), year.stars.to_string()])
+        .collect();
+
+    format!("{}\n## Past Years\n\n{}",
+            table(&["Day", "Title", "Description", "Stars"], &days, false),
+            table(&["Year", "Language", "Stars (50)"], &years, true))
+}
+
+// a markdown table with every column padded to its widest cell. the stars column is left alone,
+// its cells are already two wide and the stars are wider than they look
+fn table(headers: &[&str], rows: &[Vec<String>], pad_last: bool) -> String {
+    let last = headers.len() - 1;
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|col| rows.iter()
+                       .map(|row| row[col].chars().count())
+                       .chain([headers[col].len()])
+                       .max()
+                       .unwrap())
+        .collect();
+
+    let line = |cells: Vec<String>| {
+        let cells: Vec<String> = cells.into_iter()
+            .enumerate()
+            .map(|(col, cell)| match col == last && !pad_last {
+                true  => cell,
+                false => format!("{cell:<width$}", width = widths[col])
+            })
+            .collect();
+
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut text = line(headers.iter().map(|header| header.to_string()).collect());
+    text += &line(widths.iter().map(|&width| "-".repeat(width)).collect());
+
+    for row in rows {
+        text += &line(row.clone());
+    }
+
+    text
+}
+
+// swap the generated section into the README, leaving everything around the markers alone
+pub fn splice(readme: &str, section: &str) -> Option<String> {
+    let start = readme.find(BEGIN)? + BEGIN.len();
+    let end   = start + readme[start..].find(END)?;
+
+    Some(format!("{}\n{section}{}", &readme[..start], &readme[end..]))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::{Entry, Puzzle};
+
+    #[test]
+    fn test_render() {
+        let mut registry = Registry::default();
+
+        registry.set_puzzle(Puzzle { day: 1, title: "First".to_string(), description: "Do a thing".to_string() });
+        registry.set_puzzle(Puzzle { day: 2, title: "Second".to_string(), description: "Another".to_string() });
+        registry.set(Entry { day: 1, part: 1, input: "input".to_string(), answer: "5".to_string(), confirmed: true });
+        registry.set(Entry { day: 1, part: 2, input: "input".to_string(), answer: "7".to_string(), confirmed: true });
+
+        let past = PastYears {
+            years: vec![PastYear { year: 2023, language: "Rust".to_string(), stars: 22, url: "https://x".to_string() }]
+        };
+
+        let expected = "\
+| Day | Title  | Description | Stars |
+| --- | ------ | ----------- | ----- |
+| 2   | Second | Another     |    |
+| 1   | First  | Do a thing  | ★★ |
+
+## Past Years
+
+| Year              | Language | Stars (50) |
+| ----------------- | -------- | ---------- |
+| [2023](https://x) | Rust     | 22         |
+";
+
+        assert_eq!(expected, render(Path::new("/nonexistent"), &registry, &past));
+    }
+
+    #[test]
+    fn test_splice() {
+        let readme = format!("intro\n\n{BEGIN}\nold table\n{END}\n\nafter\n");
+
+        assert_eq!(Some(format!("intro\n\n{BEGIN}\nnew table\n{END}\n\nafter\n")),
+                   splice(&readme, "new table\n"));
+
+        assert_eq!(None, splice("no markers here", "new table\n"));
+    }
+}
@@ -0,0 +1,282 @@
+// start a new day: a crate from the template, its place in the workspace and the runner's table,
+// an empty sample file and empty answer slots in the registry
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::answers::{self, Entry, Registry, RegistryError};
+
+// the built-in template, used unless the workspace has a template/ directory of its own. files
+// ending in .tmpl have it stripped, and {{DAY}}, {{DD}} and {{YEAR}} are filled in everywhere
+const BUILT_IN: &[(&str, &str)] = &[
+    ("Cargo.toml.tmpl", include_str!("../templates/day/Cargo.toml.tmpl")),
+    ("src/lib.rs.tmpl", include_str!("../templates/day/src/lib.rs.tmpl")),
+];
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    Io { path: PathBuf, error: std::io::Error },
+    Registry(RegistryError),
+
+    // a workspace file we need to add the day to doesn't look the way we expect
+    Edit { path: PathBuf, message: String }
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path)    => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, error }     => write!(f, "{}: {error}", path.display()),
+            ScaffoldError::Registry(error)        => write!(f, "{error}"),
+            ScaffoldError::Edit { path, message } => write!(f, "couldn't update {}: {message}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<RegistryError> for ScaffoldError {
+    fn from(error: RegistryError) -> Self {
+        ScaffoldError::Registry(error)
+    }
+}
+
+fn io_error(path: &Path) -> impl Fn(std::io::Error) -> ScaffoldError + '_ {
+    move |error| ScaffoldError::Io { path: path.to_path_buf(), error }
+}
+
+// where a team's own template lives, if it has one
+pub fn template_dir(root: &Path) -> PathBuf {
+    root.join("template")
+}
+
+// the template's files as (relative path, contents), from a directory or the built-in one
+pub fn load_template(dir: Option<&Path>) -> Result<Vec<(PathBuf, String)>, ScaffoldError> {
+    let Some(dir) = dir else {
+        return Ok(BUILT_IN.iter()
+                          .map(|&(path, text)| (PathBuf::from(path), text.to_string()))
+                          .collect())
+    };
+
+    let mut files = vec![];
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current).map_err(io_error(&current))? {
+            let path = entry.map_err(io_error(&current))?.path();
+
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let text = fs::read_to_string(&path).map_err(io_error(&path))?;
+                files.push((path.strip_prefix(dir).unwrap().to_path_buf(), text));
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+pub fn render(text: &str, year: u16, day: u8) -> String {
+    text.replace("{{DAY}}", &day.to_string())
+        .replace("{{DD}}", &format!("{day:02}"))
+        .replace("{{YEAR}}", &year.to_string())
+}
+
+// create dayNN/ under the root and hook it up everywhere the workspace lists its days.
+// returns the new crate's directory
+pub fn create_day(root: &Path, year: u16, day: u8, template: &[(PathBuf, String)])
+    -> Result<PathBuf, ScaffoldError>
+{
+    let name = format!("day{day:02}");
+    let dir  = root.join(&name);
+
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir))
+    }
+
+    // make all the edits in memory first so a workspace we can't make sense of is left alone
+    let edits = [
+        edit(&root.join("Cargo.toml"),
+             &format!("    \"{name}\","),
+             |line| line.trim().starts_with("\"day"))?,
+
+        edit(&root.join("aoc/Cargo.toml"),
+             &format!("{name} = {{ path = \"../{name}\" }}"),
+             |line| line.starts_with("day") && line.contains("path"))?,
+
+        edit(&root.join("aoc/src/days.rs"),
+             &format!("    day!({name}::Day{day:02}),"),
+             |line| line.trim().starts_with("day!(day"))?,
+    ];
+
+    for (path, text) in template {
+        let path = dir.join(path.to_string_lossy().trim_end_matches(".tmpl"));
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_error(parent))?;
+        }
+        fs::write(&path, render(text, year, day)).map_err(io_error(&path))?;
+    }
+
+    // the template may bring its own sample, otherwise start with an empty one
+    let sample = dir.join("sample.txt");
+    if !sample.exists() {
+        fs::write(&sample, "").map_err(io_error(&sample))?;
+    }
+
+    for (path, text) in edits {
+        fs::write(&path, text).map_err(io_error(&path))?;
+    }
+
+    add_answer_slots(year, day)?;
+
+    Ok(dir)
+}
+
+// insert a line among its peers (the existing days), keeping them sorted
+fn edit(path: &Path, line: &str, is_peer: impl Fn(&str) -> bool) -> Result<(PathBuf, String), ScaffoldError> {
+    let text = fs::read_to_string(path).map_err(io_error(path))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let peers: Vec<usize> = (0..lines.len()).filter(|&i| is_peer(lines[i])).collect();
+
+    let Some(&last) = peers.last() else {
+        return Err(ScaffoldError::Edit { path: path.to_path_buf(), message: "no days listed to add to".to_string() })
+    };
+
+    if peers.iter().any(|&i| lines[i] == line) {
+        return Err(ScaffoldError::Edit { path: path.to_path_buf(), message: format!("already has {}", line.trim()) })
+    }
+
+    let at = peers.iter()
+                  .find(|&&i| lines[i] > line)
+                  .copied()
+                  .unwrap_or(last + 1);
+
+    lines.insert(at, line);
+
+    Ok((path.to_path_buf(), lines.join("\n") + "\n"))
+}
+
+// empty answers for both parts of the sample and the real input, to be filled in as they're found
+fn add_answer_slots(year: u16, day: u8) -> Result<(), ScaffoldError> {
+    let path = answers::path(year);
+    let mut registry = Registry::load(&path)?;
+
+    for input in ["sample", "input"] {
+        for part in 1..=2 {
+            if registry.get(day, part, input).is_none() {
+                registry.set(Entry { day, part, input: input.to_string(), answer: String::new(), confirmed: false });
+            }
+        }
+    }
+
+    registry.save(&path).map_err(io_error(&path))
+}
+
+
+/* Samples */
+
+// the first code block in the puzzle text that follows a mention of an example, or failing that
+// the first code block at all
+pub fn extract_sample(html: &str) -> Option<String> {
+    let blocks: Vec<(usize, &str)> = html.match_indices("<pre><code>")
+        .filter_map(|(start, open)| {
+            let body = &html[start + open.len()..];
+            let end  = body.find("</code></pre>")?;
+            Some((start, &body[..end]))
+        })
+        .collect();
+
+    let after_example = blocks.iter().find(|&&(start, _)| {
+        let before = &html[..start];
+        let paragraph = before.rfind("<p>").map_or(before, |i| &before[i..]);
+
+        paragraph.to_lowercase().contains("example")
+    });
+
+    let (_, code) = after_example.or(blocks.first())?;
+
+    Some(decode(&strip_tags(code)))
+}
+
+// the puzzle's title out of its heading, "--- Day 8: Playground ---"
+pub fn extract_title(html: &str) -> Option<String> {
+    let start   = html.find("<h2>")? + "<h2>".len();
+    let heading = &html[start..start + html[start..].find("</h2>")?];
+
+    let heading = decode(&strip_tags(heading));
+    let (_, title) = heading.trim_matches(|c| c == '-' || c == ' ').split_once(": ")?;
+
+    Some(title.to_string())
+}
+
+// puzzle text highlights parts of samples with <em> and friends
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => ()
+        }
+    }
+
+    text
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let template = load_template(None).unwrap();
+        let (_, lib) = template.iter().find(|(path, _)| path.ends_with("lib.rs.tmpl")).unwrap();
+
+        let lib = render(lib, 2025, 8);
+
+        assert!(lib.starts_with("// https://adventofcode.com/2025/day/8\n"));
+        assert!(lib.contains("pub struct Day08;"));
+        assert!(lib.contains("const DAY: u8 = 8;"));
+        assert!(!lib.contains("{{"));
+    }
+
+    #[test]
+    fn test_extract_sample() {
+        let html = "<article><p>Some story.</p><pre><code>not this</code></pre>\
+                    <p>For example, consider:</p>\
+                    <pre><code>[.##.] (3) {3,&lt;5&gt;}\n<em>7</em>,1\n</code></pre></article>";
+
+        assert_eq!(Some("[.##.] (3) {3,<5>}\n7,1\n".to_string()), extract_sample(html));
+
+        assert_eq!(Some("only one".to_string()), extract_sample("<pre><code>only one</code></pre>"));
+        assert_eq!(None, extract_sample("<p>no code here</p>"));
+    }
+
+    #[test]
+    fn test_extract_title() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 8: Playground ---</h2><p>Equipped";
+
+        assert_eq!(Some("Playground".to_string()), extract_title(html));
+        assert_eq!(None, extract_title("<p>no heading</p>"));
+    }
+}
@@ -0,0 +1,248 @@
+// the adventofcode.com session cookie. it's loaded from AOC_SESSION or a per-user config file,
+// never from anything inside the repo, and it's kept out of everything we print
+
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+const REDACTED: &str = "<redacted>";
+
+// every session cookie seen so far starts with the hex for "Salted__"
+const TOKEN_PREFIX: &str = "53616c7465645f5f";
+
+// the token we loaded, so redact() can scrub it even if it doesn't look like the usual shape
+static LOADED: OnceLock<String> = OnceLock::new();
+
+pub struct Session {
+    token     : String,
+    pub source: Source
+}
+
+pub enum Source {
+    Environment,
+    File(PathBuf)
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Missing { file: PathBuf },
+    Empty { file: PathBuf },
+    Insecure { file: PathBuf, mode: u32 },
+    Io { file: PathBuf, error: std::io::Error }
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::Missing { file } =>
+                write!(f, "no session token: set AOC_SESSION or put it in {}", file.display()),
+
+            SessionError::Empty { file } =>
+                write!(f, "{} is empty", file.display()),
+
+            SessionError::Insecure { file, mode } =>
+                write!(f, "{} is readable by other users (mode {:o}), refusing to use it. run: chmod 600 {}",
+                       file.display(), mode & 0o777, file.display()),
+
+            SessionError::Io { file, error } =>
+                write!(f, "couldn't read {}: {error}", file.display())
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl Session {
+    // AOC_SESSION wins over the config file
+    pub fn load() -> Result<Session, SessionError> {
+        if let Ok(token) = std::env::var("AOC_SESSION")
+            && !token.trim().is_empty()
+        {
+            return Ok(Session::new(token.trim(), Source::Environment))
+        }
+
+        let file = config_file();
+        if !file.exists() {
+            return Err(SessionError::Missing { file })
+        }
+
+        check_permissions(&file)?;
+
+        let token = std::fs::read_to_string(&file)
+            .map_err(|error| SessionError::Io { file: file.clone(), error })?;
+
+        // tolerate the file being pasted as "session=..." straight out of the browser
+        let token = token.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token);
+
+        if token.is_empty() {
+            return Err(SessionError::Empty { file })
+        }
+
+        Ok(Session::new(token, Source::File(file)))
+    }
+
+    fn new(token: &str, source: Source) -> Session {
+        let _ = LOADED.set(token.to_string());
+
+        Session {
+            token: token.to_string(),
+            source
+        }
+    }
+
+    // the only way to get at the raw token, for the Cookie header
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+// never print the token by accident
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Session({REDACTED})")
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Environment => write!(f, "AOC_SESSION"),
+            Source::File(path)  => write!(f, "{}", path.display())
+        }
+    }
+}
+
+// AOC_SESSION_FILE, otherwise aoc/session in the user's config directory
+pub fn config_file() -> PathBuf {
+    if let Ok(path) = std::env::var("AOC_SESSION_FILE") {
+        return PathBuf::from(path)
+    }
+
+    let config_dir = if cfg!(windows) {
+        std::env::var("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|_| std::env::var("HOME").map(|home| Path::new(&home).join(".config")))
+    };
+
+    config_dir.unwrap_or_default()
+              .join("aoc")
+              .join("session")
+}
+
+#[cfg(unix)]
+fn check_permissions(file: &Path) -> Result<(), SessionError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = std::fs::metadata(file)
+        .map_err(|error| SessionError::Io { file: file.to_path_buf(), error })?
+        .permissions()
+        .mode();
+
+    // any bit for group or other is too much
+    if mode & 0o077 != 0 {
+        return Err(SessionError::Insecure { file: file.to_path_buf(), mode })
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_file: &Path) -> Result<(), SessionError> {
+    Ok(())
+}
+
+// scrub the loaded token, and anything else shaped like one, out of a line we're about to print
+pub fn redact(text: &str) -> String {
+    let mut text = match LOADED.get() {
+        Some(token) => text.replace(token.as_str(), REDACTED),
+        None        => text.to_string()
+    };
+
+    for range in find_tokens(&text).into_iter().rev() {
+        text.replace_range(range, REDACTED);
+    }
+
+    text
+}
+
+// the byte ranges of anything in this text that looks like a session token: a run of hex digits
+// that either starts with the usual prefix or is too long to be a typical hash. a long run of
+// plain decimal digits is just a big number (day 3's input is lines of 100 of them)
+pub fn find_tokens(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_hexdigit() {
+            i += 1;
+            continue
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_hexdigit() {
+            i += 1;
+        }
+
+        let run = &text[start..i];
+        let has_prefix = run.len() >= 32 && run[..TOKEN_PREFIX.len()].eq_ignore_ascii_case(TOKEN_PREFIX);
+
+        let has_letter = run.bytes().any(|b| b.is_ascii_alphabetic());
+
+        if has_prefix || (run.len() >= 96 && has_letter) {
+            tokens.push(start..i);
+        }
+    }
+
+    tokens
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // built at runtime so `aoc doctor` doesn't flag this file
+    fn token() -> String {
+        format!("{TOKEN_PREFIX}{}", "0123456789abcdef".repeat(7))
+    }
+
+    #[test]
+    fn test_find_tokens() {
+        let line = format!("SESSION={}", token());
+        assert_eq!(vec![8..8+token().len()], find_tokens(&line));
+
+        // the usual prefix is enough, even on a shorter token
+        let short = format!("{}0123456789ABCDEF", TOKEN_PREFIX.to_uppercase());
+        assert_eq!(1, find_tokens(&short).len());
+
+        // a sha256 is long and hex but not a token
+        let sha = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert!(find_tokens(sha).is_empty());
+        assert!(find_tokens("no tokens here, just 1234 and cafe").is_empty());
+
+        // nor is a long line of digits
+        let digits = "8119".repeat(25);
+        assert!(find_tokens(&digits).is_empty());
+        assert_eq!(digits, redact(&digits));
+    }
+
+    #[test]
+    fn test_redact() {
+        let message = format!("request with session={} failed", token());
+        assert_eq!("request with session=<redacted> failed", redact(&message));
+        assert_eq!("nothing to hide", redact("nothing to hide"));
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let session = Session { token: token(), source: Source::Environment };
+        assert_eq!("Session(<redacted>)", format!("{session:?}"));
+    }
+}
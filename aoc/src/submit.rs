@@ -0,0 +1,231 @@
+// post an answer to adventofcode.com and make sense of the page that comes back
+
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    TooRecent,
+    Unrecognised(String)
+}
+
+// the outcome, and how long the site wants us to wait before the next submission
+#[derive(Debug, PartialEq)]
+pub struct Reply {
+    pub outcome: Outcome,
+    pub wait   : Option<Duration>
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Client(ClientError),
+    Http { url: String, status: u16 }
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::Client(error) => write!(f, "{error}"),
+            SubmitError::Http { url, status } => {
+                write!(f, "{url} returned HTTP {status}")?;
+                if *status == 400 {
+                    write!(f, " (is the session token expired?)")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(error: ClientError) -> Self {
+        SubmitError::Client(error)
+    }
+}
+
+pub fn submit(client: &Client, year: u16, day: u8, part: u8, answer: &str) -> Result<Reply, SubmitError> {
+    let path  = format!("/{year}/day/{day}/answer");
+    let level = part.to_string();
+
+    let response = client.post_form(&path, &[("level", &level), ("answer", answer)])?;
+
+    if response.status != 200 {
+        return Err(SubmitError::Http { url: client.url(&path), status: response.status })
+    }
+
+    Ok(parse_reply(&response.body))
+}
+
+// the interesting part of the page is the text of its <article>
+pub fn parse_reply(html: &str) -> Reply {
+    let text = article_text(html);
+
+    let outcome =
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Outcome::TooHigh
+            } else if text.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::TooRecent
+        } else {
+            Outcome::Unrecognised(text.clone())
+        };
+
+    Reply { outcome, wait: parse_wait(&text) }
+}
+
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html
+    };
+
+    // drop the tags, then collapse the whitespace they leave behind
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => ()
+        }
+    }
+
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// "You have 1m 23s left to wait" after answering too soon, or "Please wait one minute before
+// trying again" after a wrong answer
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ")
+        && let Some(end) = text[start..].find(" left to wait")
+    {
+        let mut secs = 0;
+
+        for token in text[start + 9 .. start + end].split_whitespace() {
+            let (number, per) = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find_map(|(unit, per)| Some((token.strip_suffix(unit)?, per)))?;
+
+            secs += number.parse::<u64>().ok()? * per;
+        }
+
+        return Some(Duration::from_secs(secs))
+    }
+
+    let start = text.find("wait ")?;
+    let mut words = text[start + 5 ..].split_whitespace();
+
+    let number = match words.next()? {
+        "one"  => 1,
+        number => number.parse().ok()?
+    };
+
+    let unit = words.next()?;
+
+    if unit.starts_with("minute") {
+        Some(Duration::from_secs(number * 60))
+    } else if unit.starts_with("second") {
+        Some(Duration::from_secs(number))
+    } else {
+        None
+    }
+}
+
+
+/* Cooldown */
+
+// when we're next allowed to submit, as unix seconds, kept between runs in a file at the root
+pub fn cooldown_path() -> PathBuf {
+    common::root().join(".aoc-cooldown")
+}
+
+// how much longer we have to wait, if at all
+pub fn cooldown_remaining(now: SystemTime) -> Option<Duration> {
+    let until: u64 = std::fs::read_to_string(cooldown_path()).ok()?
+                                                              .trim()
+                                                              .parse()
+                                                              .ok()?;
+
+    let now = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+
+    (until > now).then(|| Duration::from_secs(until - now))
+}
+
+pub fn start_cooldown(now: SystemTime, wait: Duration) -> std::io::Result<()> {
+    let until = now.duration_since(UNIX_EPOCH)
+                   .unwrap_or_default()
+                   .saturating_add(wait)
+                   .as_secs();
+
+    std::fs::write(cooldown_path(), until.to_string())
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(article: &str) -> Reply {
+        parse_reply(&format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>"))
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(Reply { outcome: Outcome::Correct, wait: None },
+                   reply("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."));
+
+        assert_eq!(Reply { outcome: Outcome::TooHigh, wait: Some(Duration::from_secs(60)) },
+                   reply("That's not the right answer; your answer is too high.  Please wait one minute before trying again."));
+
+        assert_eq!(Reply { outcome: Outcome::TooLow, wait: Some(Duration::from_secs(60)) },
+                   reply("That's not the right answer; your answer is too low.  Please wait one minute before trying again."));
+
+        assert_eq!(Reply { outcome: Outcome::Wrong, wait: Some(Duration::from_secs(300)) },
+                   reply("That's not the right answer.  If you're stuck, ask on the subreddit.  Please wait 5 minutes before trying again."));
+
+        assert_eq!(Reply { outcome: Outcome::AlreadySolved, wait: None },
+                   reply("You don't seem to be solving the right level.  Did you already complete it?"));
+
+        assert_eq!(Reply { outcome: Outcome::TooRecent, wait: Some(Duration::from_secs(83)) },
+                   reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."));
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(Some(Duration::from_secs(3723)), parse_wait("You have 1h 2m 3s left to wait."));
+
+        // whatever the page says, a unit we don't know is no reason to panic
+        assert_eq!(None, parse_wait("You have 1m 23é left to wait."));
+        assert_eq!(None, parse_wait("You have 5 left to wait."));
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        assert_eq!(Outcome::Unrecognised("Something new".to_string()), reply("Something <b>new</b>").outcome);
+    }
+}
@@ -0,0 +1,61 @@
+mod support;
+
+use std::fs;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+const MANIFOLD: &str = "..S..\n.....\n..^..\n.....\n";
+
+#[test]
+fn saves_a_gif_and_stills() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir(dir.path().join("day07")).unwrap();
+    fs::write(dir.path().join("day07/sample.txt"), MANIFOLD).unwrap();
+
+    let output = aoc(dir.path(), &server).args(["animate", "7", "--sample", "--gif", "beams.gif"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Saved 4 frames to beams.gif"));
+
+    let gif = fs::read(dir.path().join("beams.gif")).unwrap();
+    assert!(gif.starts_with(b"GIF89a\x14\x00\x10\x00"));
+
+    let output = aoc(dir.path(), &server).args(["animate", "7", "--sample", "--ppm", "frames", "--scale", "1"])
+                                         .output()
+                                         .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let last = fs::read(dir.path().join("frames/frame0003.ppm")).unwrap();
+    assert!(last.starts_with(b"P6\n5 4\n255\n"));
+    assert!(!dir.path().join("frames/frame0004.ppm").exists());
+}
+
+#[test]
+fn plays_in_the_terminal() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("manifold.txt"), MANIFOLD).unwrap();
+
+    let output = aoc(dir.path(), &server).args(["animate", "7", "--input", "manifold.txt", "--delay", "0"])
+                                         .output()
+                                         .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let stdout = stdout(&output);
+    assert!(stdout.contains("\x1b[48;2;"));
+    assert!(stdout.ends_with("frame 4 of 4\n"));
+}
+
+#[test]
+fn needs_a_day_with_a_simulation() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("turns.txt"), "L68\n").unwrap();
+
+    let output = aoc(dir.path(), &server).args(["animate", "1", "--input", "turns.txt"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("day 1 has no simulation to animate"));
+}
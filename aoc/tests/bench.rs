@@ -0,0 +1,68 @@
+mod support;
+
+use std::fs;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+#[test]
+fn records_each_run_and_compares_with_the_last() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir(dir.path().join("day01")).unwrap();
+    fs::write(dir.path().join("day01/input.txt"), "L68\nR48\nL5\n").unwrap();
+
+    let bench = || aoc(dir.path(), &server).args(["bench", "1", "--samples", "3", "--warmup", "0"])
+                                            .output()
+                                            .unwrap();
+
+    let first = bench();
+    assert!(first.status.success(), "{}", stderr(&first));
+    assert!(!stdout(&first).contains('%'));
+
+    let history = fs::read_to_string(dir.path().join("bench-history.tsv")).unwrap();
+    let stages: Vec<&str> = history.lines()
+                                   .map(|line| line.split('\t').nth(3).unwrap())
+                                   .collect();
+    assert_eq!(vec!["parse", "part1", "part2"], stages);
+
+    let second = bench();
+    assert!(second.status.success(), "{}", stderr(&second));
+    assert_eq!(3, stdout(&second).matches('%').count());
+
+    let history = fs::read_to_string(dir.path().join("bench-history.tsv")).unwrap();
+    assert_eq!(6, history.lines().count());
+}
+
+#[test]
+fn skips_a_missing_input() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["bench", "4"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert!(stdout(&output).contains("(missing day04/input.txt)"));
+    assert!(!dir.path().join("bench-history.tsv").exists());
+}
+
+#[test]
+fn times_another_file_under_its_own_name() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("stress.txt"), "L68\nR48\nL5\n").unwrap();
+
+    let output = aoc(dir.path(), &server)
+        .args(["bench", "1", "--input", "stress.txt", "--samples", "3", "--warmup", "0"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let history = fs::read_to_string(dir.path().join("bench-history.tsv")).unwrap();
+    assert!(history.lines().all(|line| line.split('\t').nth(2) == Some("stress")));
+
+    let output = aoc(dir.path(), &server).args(["bench", "all", "--input", "stress.txt"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--input needs a single day"));
+}
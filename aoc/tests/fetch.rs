@@ -0,0 +1,154 @@
+mod support;
+
+use std::fs;
+
+use support::{aoc, stderr, MockServer, TempDir, SESSION};
+
+#[test]
+fn downloads_and_caches_the_input() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "1,2\n3,4\n".to_string())]);
+
+    let output = aoc(dir.path(), &server).args(["fetch", "9"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let cached = fs::read_to_string(dir.path().join("day09/input.txt")).unwrap();
+    assert_eq!("1,2\n3,4\n", cached);
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("/2025/day/9/input", requests[0].path);
+    assert_eq!(Some(format!("session={SESSION}").as_str()), requests[0].header("cookie"));
+}
+
+#[test]
+fn asks_for_the_requested_year() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+
+    let output = aoc(dir.path(), &server).args(["fetch", "5", "--year", "2023"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert_eq!("/2023/day/5/input", server.requests()[0].path);
+
+    // kept apart from this year's day 5
+    assert_eq!("input", fs::read_to_string(dir.path().join("inputs/2023/day05/input.txt")).unwrap());
+    assert!(!dir.path().join("day05/input.txt").exists());
+}
+
+#[test]
+fn sends_a_default_user_agent() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+
+    aoc(dir.path(), &server).args(["fetch", "1"]).output().unwrap();
+
+    let user_agent = server.requests()[0].header("user-agent").unwrap().to_string();
+    assert!(user_agent.contains("github.com/jasonincanada/aoc-2025"), "{user_agent}");
+}
+
+#[test]
+fn sends_a_configured_user_agent() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string()),
+                                        (200, "input".to_string())]);
+
+    aoc(dir.path(), &server)
+        .args(["fetch", "1", "--user-agent", "from the flag"])
+        .output()
+        .unwrap();
+
+    aoc(dir.path(), &server)
+        .args(["fetch", "2"])
+        .env("AOC_USER_AGENT", "from the environment")
+        .output()
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(Some("from the flag"),        requests[0].header("user-agent"));
+    assert_eq!(Some("from the environment"), requests[1].header("user-agent"));
+}
+
+#[test]
+fn refuses_to_overwrite_a_cached_input() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "new input".to_string())]);
+
+    fs::create_dir_all(dir.path().join("day03")).unwrap();
+    fs::write(dir.path().join("day03/input.txt"), "old input").unwrap();
+
+    let output = aoc(dir.path(), &server).args(["fetch", "3"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("already exists"), "{}", stderr(&output));
+
+    // and it didn't even ask the server
+    assert!(server.requests().is_empty());
+    assert_eq!("old input", fs::read_to_string(dir.path().join("day03/input.txt")).unwrap());
+}
+
+#[test]
+fn reports_http_errors() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![
+        (404, "Please don't repeatedly request this endpoint before it unlocks!".to_string()),
+        (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+        (500, "".to_string())]);
+
+    let not_found = aoc(dir.path(), &server).args(["fetch", "12"]).output().unwrap();
+    assert!(!not_found.status.success());
+    assert!(stderr(&not_found).contains("HTTP 404"));
+    assert!(stderr(&not_found).contains("unlocked"));
+    assert!(stderr(&not_found).contains("before it unlocks"));
+
+    let bad_session = aoc(dir.path(), &server).args(["fetch", "12"]).output().unwrap();
+    assert!(stderr(&bad_session).contains("HTTP 400"));
+    assert!(stderr(&bad_session).contains("session"));
+
+    let server_error = aoc(dir.path(), &server).args(["fetch", "12"]).output().unwrap();
+    assert!(stderr(&server_error).contains("HTTP 500"));
+
+    // nothing gets cached when the download fails
+    assert!(!dir.path().join("day12/input.txt").exists());
+}
+
+#[test]
+fn needs_a_session_token() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server)
+        .args(["fetch", "1"])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("AOC_SESSION"));
+}
+
+#[test]
+fn refuses_days_past_the_end_of_the_year() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["fetch", "13"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("2025 only has 12 days"), "{}", stderr(&output));
+
+    // but earlier years went up to 25
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+    let output = aoc(dir.path(), &server).args(["fetch", "25", "--year", "2024"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn refuses_days_that_are_not_unlocked_yet() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["fetch", "1", "--year", "2099"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("isn't unlocked yet"), "{}", stderr(&output));
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,99 @@
+mod support;
+
+use std::fs;
+use std::path::Path;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+// just enough of a workspace for `aoc new` to add a day to
+fn workspace() -> TempDir {
+    let dir  = TempDir::new();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    fs::create_dir_all(dir.path().join("aoc/src")).unwrap();
+
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+        fs::copy(root.join(file), dir.path().join(file)).unwrap();
+    }
+
+    dir
+}
+
+#[test]
+fn creates_a_day_and_adds_it_everywhere() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["new", "8"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let lib = fs::read_to_string(dir.path().join("day08/src/lib.rs")).unwrap();
+    assert!(lib.starts_with("// https://adventofcode.com/2025/day/8\n"));
+    assert!(lib.contains("impl Solution for Day08"));
+
+    assert_eq!("", fs::read_to_string(dir.path().join("day08/sample.txt")).unwrap());
+
+    let members = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert!(members.contains("    \"day07\",\n    \"day08\",\n    \"day09\",\n"));
+
+    let dependencies = fs::read_to_string(dir.path().join("aoc/Cargo.toml")).unwrap();
+    assert!(dependencies.contains("day08 = { path = \"../day08\" }\nday09"));
+
+    let days = fs::read_to_string(dir.path().join("aoc/src/days.rs")).unwrap();
+    assert!(days.contains("    day!(day07::Day07),\n    day!(day08::Day08),\n    day!(day09::Day09),\n"));
+
+    let registry = fs::read_to_string(dir.path().join("answers/2025.toml")).unwrap();
+    assert_eq!(4, registry.matches("day = 8").count());
+    assert_eq!(4, registry.matches("answer = \"\"").count());
+
+    // and never over the top of an existing day
+    let output = aoc(dir.path(), &server).args(["new", "8"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("already exists"));
+}
+
+#[test]
+fn uses_the_workspace_template() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir_all(dir.path().join("template/src")).unwrap();
+    fs::write(dir.path().join("template/src/lib.rs.tmpl"), "// day {{DAY}} of {{YEAR}}, crate day{{DD}}\n").unwrap();
+    fs::write(dir.path().join("template/NOTES.md"), "# Day {{DAY}}\n").unwrap();
+
+    let output = aoc(dir.path(), &server).args(["new", "8"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert_eq!("// day 8 of 2025, crate day08\n", fs::read_to_string(dir.path().join("day08/src/lib.rs")).unwrap());
+    assert_eq!("# Day 8\n", fs::read_to_string(dir.path().join("day08/NOTES.md")).unwrap());
+}
+
+#[test]
+fn fetches_the_input_and_the_sample() {
+    let dir    = workspace();
+    let page   = "<article><h2>--- Day 8: Playground ---</h2><p>For example:</p><pre><code>1,2\n<em>3</em>,4\n</code></pre></article>";
+    let server = MockServer::start(vec![(200, "real input\n".to_string()), (200, page.to_string())]);
+
+    let output = aoc(dir.path(), &server).args(["new", "8", "--fetch"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert_eq!("real input\n", fs::read_to_string(dir.path().join("day08/input.txt")).unwrap());
+    assert_eq!("1,2\n3,4\n", fs::read_to_string(dir.path().join("day08/sample.txt")).unwrap());
+
+    let paths: Vec<String> = server.requests().into_iter().map(|request| request.path).collect();
+    assert_eq!(vec!["/2025/day/8/input", "/2025/day/8"], paths);
+    assert!(stdout(&output).contains("Saved"));
+
+    let registry = fs::read_to_string(dir.path().join("answers/2025.toml")).unwrap();
+    assert!(registry.contains("[[puzzle]]\nday = 8\ntitle = \"Playground\"\n"));
+}
+
+#[test]
+fn refuses_a_day_past_the_end() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["new", "13"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(!dir.path().join("day13").exists());
+}
@@ -0,0 +1,95 @@
+mod support;
+
+use std::fs;
+use std::path::Path;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+// the committed README has to match the committed registry
+#[test]
+fn readme_is_up_to_date() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+    let root   = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let output = aoc(dir.path(), &server).env("AOC_ROOT", root)
+                                         .args(["readme", "--check"])
+                                         .output()
+                                         .unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn rewrites_only_the_marked_section() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir_all(dir.path().join("answers")).unwrap();
+    fs::create_dir_all(dir.path().join("day01/src")).unwrap();
+    fs::write(dir.path().join("day01/src/lib.rs"), "").unwrap();
+
+    fs::write(dir.path().join("answers/2025.toml"), "\
+[[puzzle]]
+day = 1
+title = \"Secret Entrance\"
+description = \"Turn a dial\"
+
+[[puzzle]]
+day = 2
+title = \"Gift Shop\"
+description = \"Find invalid IDs\"
+
+[[answer]]
+day = 1
+part = 1
+input = \"input\"
+answer = \"3\"
+confirmed = true
+
+[[answer]]
+day = 1
+part = 2
+input = \"input\"
+answer = \"6\"
+confirmed = false
+").unwrap();
+
+    fs::write(dir.path().join("README.md"), "\
+# Title
+
+<!-- BEGIN aoc readme -->
+stale
+<!-- END aoc readme -->
+
+Footer
+").unwrap();
+
+    let check = aoc(dir.path(), &server).args(["readme", "--check"]).output().unwrap();
+    assert!(!check.status.success());
+    assert!(stderr(&check).contains("out of date"));
+
+    let output = aoc(dir.path(), &server).arg("readme").output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Updated"));
+
+    let readme = fs::read_to_string(dir.path().join("README.md")).unwrap();
+
+    assert!(readme.starts_with("# Title\n\n<!-- BEGIN aoc readme -->\n| Day |"));
+    assert!(readme.ends_with("<!-- END aoc readme -->\n\nFooter\n"));
+    assert!(readme.contains(&format!("| 2   | {:<37} | Find invalid IDs |    |\n", "Gift Shop")), "{readme}");
+    assert!(readme.contains("| 1   | [Secret Entrance](./day01/src/lib.rs) | Turn a dial      | ★  |\n"), "{readme}");
+    assert!(!readme.contains("stale"));
+}
+
+#[test]
+fn needs_markers() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("README.md"), "# Nothing to see\n").unwrap();
+
+    let output = aoc(dir.path(), &server).arg("readme").output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no section to rewrite"));
+}
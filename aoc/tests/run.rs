@@ -0,0 +1,78 @@
+mod support;
+
+use std::fs;
+use std::io::Write;
+use std::process::Stdio;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+#[test]
+fn points_at_an_input_that_does_not_parse() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    // day 9's input saved as day 1's
+    fs::create_dir(dir.path().join("day01")).unwrap();
+    fs::write(dir.path().join("day01/input.txt"), "7,1\n11,1\n").unwrap();
+
+    let output = aoc(dir.path(), &server).args(["run", "1"]).output().unwrap();
+    assert!(!output.status.success());
+
+    let stdout = stdout(&output);
+    assert!(stdout.contains("day01/input.txt:1:1: expected L or R, found \"7\""), "{stdout}");
+    assert!(stdout.contains("    7,1\n    ^"), "{stdout}");
+    assert!(stderr(&output).contains("didn't parse"));
+}
+
+#[test]
+fn finds_the_day_files_from_anywhere() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir_all(dir.path().join("day01/elsewhere")).unwrap();
+    fs::write(dir.path().join("day01/sample.txt"), "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+
+    let output = aoc(dir.path(), &server).current_dir(dir.path().join("day01/elsewhere"))
+                                         .args(["run", "1", "--part", "1"])
+                                         .output()
+                                         .unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Sample part 1: 3"));
+}
+
+#[test]
+fn solves_the_inputs_given() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("one.txt"), "R50\n").unwrap();
+    fs::write(dir.path().join("two.txt"), "L50\nR100\n").unwrap();
+
+    let mut child = aoc(dir.path(), &server)
+        .args(["run", "1", "--part", "1", "--input", "one.txt", "-i", "-", "-i", "two.txt"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(b"R150\nL100\n").unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    assert_eq!("Day 1\none.txt\nPart 1:        1\n(stdin)\nPart 1:        2\ntwo.txt\nPart 1:        2\n",
+               stdout(&output));
+}
+
+#[test]
+fn only_takes_inputs_for_one_day() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["run", "all", "--input", "x.txt"]).output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--input needs a single day"));
+}
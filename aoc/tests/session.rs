@@ -0,0 +1,155 @@
+mod support;
+
+use std::fs;
+use std::path::Path;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+// shaped like a real session cookie, built at runtime so `aoc doctor` doesn't flag this file
+fn token() -> String {
+    format!("53616c7465645f5f{}", "0123456789abcdef".repeat(7))
+}
+
+#[cfg(unix)]
+fn write_session_file(dir: &Path, contents: &str, mode: u32) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join("session");
+    fs::write(&path, contents).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(mode)).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn reads_the_token_from_the_config_file() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+
+    write_session_file(dir.path(), &format!("{}\n", token()), 0o600);
+
+    let output = aoc(dir.path(), &server)
+        .args(["fetch", "1"])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(Some(format!("session={}", token()).as_str()), server.requests()[0].header("cookie"));
+}
+
+#[cfg(unix)]
+#[test]
+fn refuses_a_config_file_other_users_can_read() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+
+    for mode in [0o640, 0o604, 0o644] {
+        write_session_file(dir.path(), &token(), mode);
+
+        let output = aoc(dir.path(), &server)
+            .args(["fetch", "1"])
+            .env_remove("AOC_SESSION")
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("chmod 600"), "{}", stderr(&output));
+    }
+
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn the_environment_wins_over_the_config_file() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(200, "input".to_string())]);
+
+    // this would be refused if it were ever read
+    fs::write(dir.path().join("session"), "from the file").unwrap();
+
+    let output = aoc(dir.path(), &server)
+        .args(["fetch", "1"])
+        .env("AOC_SESSION", "from the environment")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(Some("session=from the environment"), server.requests()[0].header("cookie"));
+}
+
+#[test]
+fn the_token_never_shows_up_in_errors() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![(400, format!("bad session {}", token()))]);
+
+    let output = aoc(dir.path(), &server)
+        .args(["fetch", "1"])
+        .env("AOC_SESSION", token())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("HTTP 400"));
+    assert!(stderr(&output).contains("<redacted>"));
+    assert!(!stderr(&output).contains(&token()));
+    assert!(!stdout(&output).contains(&token()));
+}
+
+#[test]
+fn doctor_finds_a_committed_token() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::create_dir_all(dir.path().join("scripts")).unwrap();
+    fs::write(dir.path().join("scripts/pull.sh"),
+              format!("#!/bin/bash\n\nSESSION={}\n", token())).unwrap();
+
+    let output = aoc(dir.path(), &server).arg("doctor").output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("scripts/pull.sh:3"), "{}", stdout(&output));
+    assert!(!stdout(&output).contains(&token()));
+}
+
+#[test]
+fn doctor_skips_ignored_files() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git").arg("-C").arg(dir.path()).args(args).status().unwrap();
+        assert!(status.success());
+    };
+
+    git(&["init", "-q"]);
+    fs::write(dir.path().join(".gitignore"), "input.txt\n").unwrap();
+
+    // an input that happens to look like a token never gets committed
+    fs::create_dir_all(dir.path().join("day03")).unwrap();
+    fs::write(dir.path().join("day03/input.txt"), format!("{}\n", token())).unwrap();
+
+    let output = aoc(dir.path(), &server).arg("doctor").output().unwrap();
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("no session tokens found"));
+
+    // but a file that's about to be committed does get looked at
+    fs::write(dir.path().join("notes.txt"), format!("{}\n", token())).unwrap();
+
+    let output = aoc(dir.path(), &server).arg("doctor").output().unwrap();
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("notes.txt:1"), "{}", stdout(&output));
+}
+
+#[test]
+fn doctor_passes_a_clean_tree() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    fs::write(dir.path().join("notes.txt"), "nothing to see here\n").unwrap();
+
+    let output = aoc(dir.path(), &server).arg("doctor").output().unwrap();
+
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(stdout(&output).contains("session: ok, loaded from AOC_SESSION"));
+    assert!(stdout(&output).contains("no session tokens found"));
+}
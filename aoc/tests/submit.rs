@@ -0,0 +1,162 @@
+mod support;
+
+use std::fs;
+use std::path::Path;
+
+use support::{aoc, stderr, stdout, MockServer, TempDir};
+
+// a recorded response page from adventofcode.com
+fn page(name: &str) -> (u16, String) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("tests/fixtures/submit/{name}.html"));
+    (200, fs::read_to_string(path).unwrap())
+}
+
+// a workspace where day 1's input makes both parts come out to 1
+fn workspace() -> TempDir {
+    let dir = TempDir::new();
+    fs::create_dir_all(dir.path().join("day01")).unwrap();
+    fs::write(dir.path().join("day01/input.txt"), "R50\n").unwrap();
+    dir
+}
+
+fn registry(dir: &TempDir) -> String {
+    fs::read_to_string(dir.path().join("answers/2025.toml")).unwrap_or_default()
+}
+
+fn guesses(dir: &TempDir) -> String {
+    fs::read_to_string(dir.path().join("answers/2025-guesses.toml")).unwrap_or_default()
+}
+
+#[test]
+fn a_correct_answer_is_recorded_everywhere() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![page("correct")]);
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("1 is correct!"));
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("POST", requests[0].method);
+    assert_eq!("/2025/day/1/answer", requests[0].path);
+    assert_eq!("level=1&answer=1", requests[0].body);
+
+    assert!(registry(&dir).contains("day = 1\npart = 1\ninput = \"input\"\nanswer = \"1\"\nconfirmed = true"));
+    assert!(guesses(&dir).contains("answer = \"1\"\nfeedback = \"correct\""));
+
+    // and once it's confirmed there's nothing left to submit
+    let again = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(!again.status.success());
+    assert!(stderr(&again).contains("already solved"));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn wrong_answers_go_in_the_guess_log() {
+    for (page_name, feedback) in [("too-high", "too-high"), ("too-low", "too-low"), ("wrong", "wrong")] {
+        let dir    = workspace();
+        let server = MockServer::start(vec![page(page_name)]);
+
+        let output = aoc(dir.path(), &server).args(["submit", "1", "2"]).output().unwrap();
+        assert!(!output.status.success());
+
+        assert!(guesses(&dir).contains(&format!("part = 2\nanswer = \"1\"\nfeedback = \"{feedback}\"")),
+                "{page_name}: {}", guesses(&dir));
+
+        assert!(registry(&dir).is_empty());
+    }
+}
+
+#[test]
+fn waits_out_the_cooldown_after_a_wrong_answer() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![page("too-high")]);
+
+    aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+
+    // part 2 has its own guesses, but the cooldown is for the whole account
+    let output = aoc(dir.path(), &server).args(["submit", "1", "2"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("wait"), "{}", stderr(&output));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn answering_too_soon_starts_a_cooldown() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![page("too-recent")]);
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("wait 37s"), "{}", stderr(&output));
+
+    // nothing was learned about the answer itself
+    assert!(guesses(&dir).is_empty());
+
+    let until: u64 = fs::read_to_string(dir.path().join(".aoc-cooldown")).unwrap().parse().unwrap();
+    assert!(until > 0);
+
+    let again = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(!again.status.success());
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn waits_and_tries_again_when_answering_too_soon() {
+    let dir    = workspace();
+    let soon   = "<article><p>You gave an answer too recently; you have to wait after submitting an \
+                  answer before trying again.  You have 1s left to wait.</p></article>";
+    let server = MockServer::start(vec![(200, soon.to_string()), page("correct")]);
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1", "--wait"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("waiting 1s"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("1 is correct!"));
+
+    let requests = server.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!(requests[0].body, requests[1].body);
+}
+
+#[test]
+fn an_already_solved_level_is_not_an_error() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![page("already-solved")]);
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Already solved"));
+    assert!(guesses(&dir).is_empty());
+}
+
+#[test]
+fn refuses_answers_the_guess_log_rules_out() {
+    let dir    = workspace();
+    let server = MockServer::start(vec![page("correct")]);
+
+    fs::create_dir_all(dir.path().join("answers")).unwrap();
+    fs::write(dir.path().join("answers/2025-guesses.toml"),
+              "[[guess]]\nday = 1\npart = 1\nanswer = \"5\"\nfeedback = \"too-high\"\n\n\
+               [[guess]]\nday = 1\npart = 1\nanswer = \"3\"\nfeedback = \"too-low\"\n").unwrap();
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("too low, the answer is above 3"), "{}", stderr(&output));
+    assert!(server.requests().is_empty());
+
+    // unless we insist
+    let forced = aoc(dir.path(), &server).args(["submit", "1", "1", "--force"]).output().unwrap();
+    assert!(forced.status.success(), "{}", stderr(&forced));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn needs_the_input() {
+    let dir    = TempDir::new();
+    let server = MockServer::start(vec![]);
+
+    let output = aoc(dir.path(), &server).args(["submit", "1", "1"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("day01/input.txt"), "{}", stderr(&output));
+}
@@ -0,0 +1,153 @@
+// helpers shared by the integration tests: a stand-in for adventofcode.com, a scratch
+// workspace directory, and a way to run the aoc binary inside it
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+pub const SESSION: &str = "not-a-real-session-token";
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method : String,
+    pub path   : String,
+    pub headers: Vec<(String, String)>,
+    pub body   : String
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// answers each incoming request with the next canned (status, body) pair, and remembers
+// what it was asked so the tests can check it afterwards
+pub struct MockServer {
+    pub url : String,
+    requests: Arc<Mutex<Vec<Request>>>
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url      = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader  = BufReader::new(stream);
+
+                let request = read_request(&mut reader);
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len());
+
+                let mut stream = reader.into_inner();
+                stream.write_all(response.as_bytes()).unwrap();
+                stream.flush().unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+
+    let mut words = line.split_whitespace();
+    let method = words.next().unwrap().to_string();
+    let path   = words.next().unwrap().to_string();
+
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break
+        }
+
+        let (key, value) = line.split_once(':').unwrap();
+        headers.push((key.trim().to_string(), value.trim().to_string()));
+    }
+
+    let length = headers.iter()
+                        .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                        .map(|(_, value)| value.parse().unwrap())
+                        .unwrap_or(0);
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+
+    Request { method, path, headers, body: String::from_utf8(body).unwrap() }
+}
+
+// a scratch directory standing in for the workspace root, removed when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "aoc-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)));
+
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+// the aoc binary, run from `dir` against `server`, with none of the caller's own settings leaking in
+pub fn aoc(dir: &Path, server: &MockServer) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+
+    command.current_dir(dir)
+           .env("AOC_BASE_URL", &server.url)
+           .env("AOC_SESSION", SESSION)
+           .env("AOC_SESSION_FILE", dir.join("session"))
+           .env("AOC_ROOT", dir)
+           .env_remove("AOC_USER_AGENT");
+
+    command
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
@@ -0,0 +1,276 @@
+// the registry of known answers, one TOML file per year in answers/. each entry is one answer
+// for one part of one day against one input file, and whether adventofcode.com has confirmed it
+// (for the samples, confirmed means it's the answer given in the puzzle text). each day's puzzle
+// title and a short description live alongside, for the README
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Solution};
+
+const HEADER: &str = "\
+# Known answers, read by `aoc run` and each day's tests, and the puzzles for `aoc readme`.
+#
+#   input     = which file the answer is for: \"sample\" is sample.txt, \"input\" is input.txt
+#   confirmed = accepted by adventofcode.com, or given in the puzzle text for a sample
+#
+# A part with no entry, or an empty answer, has no known answer yet.
+
+";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day      : u8,
+    pub part     : u8,
+    pub input    : String,
+    pub answer   : String,
+    pub confirmed: bool
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Puzzle {
+    pub day        : u8,
+    pub title      : String,
+    pub description: String
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Registry {
+    #[serde(default, rename = "puzzle")]
+    pub puzzles: Vec<Puzzle>,
+
+    #[serde(default, rename = "answer")]
+    pub answers: Vec<Entry>
+}
+
+#[derive(Debug)]
+pub enum RegistryError {
+    Io { path: PathBuf, error: std::io::Error },
+    Toml { path: PathBuf, error: toml::de::Error }
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Io { path, error }   => write!(f, "couldn't read {}: {error}", path.display()),
+            RegistryError::Toml { path, error } => write!(f, "couldn't parse {}: {error}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+// answers/{year}.toml at the root of the workspace
+pub fn path(year: u16) -> PathBuf {
+    crate::root()
+        .join("answers")
+        .join(format!("{year}.toml"))
+}
+
+impl Registry {
+    // a year with no answers yet has no file, which is the same as an empty registry
+    pub fn load(path: &Path) -> Result<Registry, RegistryError> {
+        if !path.exists() {
+            return Ok(Registry::default())
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| RegistryError::Io { path: path.to_path_buf(), error })?;
+
+        toml::from_str(&text)
+            .map_err(|error| RegistryError::Toml { path: path.to_path_buf(), error })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let toml = toml::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, format!("{HEADER}{toml}"))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&Entry> {
+        self.answers
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part && entry.input == input)
+    }
+
+    // add an entry, or replace the existing one for the same day/part/input
+    pub fn set(&mut self, entry: Entry) {
+        self.answers
+            .retain(|e| !(e.day == entry.day && e.part == entry.part && e.input == entry.input));
+
+        self.answers.push(entry);
+
+        // keep the file in a stable order: by day, samples before the real input, then part
+        self.answers
+            .sort_by(|a, b| (a.day, &a.input != "sample", &a.input, a.part)
+                      .cmp(&(b.day, &b.input != "sample", &b.input, b.part)));
+    }
+
+    pub fn puzzle(&self, day: u8) -> Option<&Puzzle> {
+        self.puzzles
+            .iter()
+            .find(|puzzle| puzzle.day == day)
+    }
+
+    // add a day's puzzle, or replace the one already there
+    pub fn set_puzzle(&mut self, puzzle: Puzzle) {
+        self.puzzles.retain(|p| p.day != puzzle.day);
+        self.puzzles.push(puzzle);
+        self.puzzles.sort_by_key(|p| p.day);
+    }
+
+    // one star for each part whose answer for the real input has been accepted
+    pub fn stars(&self, day: u8) -> usize {
+        self.answers
+            .iter()
+            .filter(|entry| entry.day == day
+                         && entry.input == "input"
+                         && entry.confirmed
+                         && !entry.answer.is_empty())
+            .count()
+    }
+}
+
+// how a freshly computed answer compares with the registry
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    MatchesUnconfirmed,
+    DiffersFromUnconfirmed { recorded: String },
+    Unknown,
+    Unsolved
+}
+
+pub fn judge(entry: Option<&Entry>, answer: &impl Answer) -> Verdict {
+    if !answer.is_solved() {
+        return Verdict::Unsolved
+    }
+
+    let answer = answer.to_string();
+
+    // `aoc new` leaves empty slots for answers that aren't known yet
+    let entry = entry.filter(|entry| !entry.answer.is_empty());
+
+    match entry {
+        None => Verdict::Unknown,
+        Some(entry) => match (entry.confirmed, entry.answer == answer) {
+            (true,  true ) => Verdict::Correct,
+            (true,  false) => Verdict::Wrong { expected: entry.answer.clone() },
+            (false, true ) => Verdict::MatchesUnconfirmed,
+            (false, false) => Verdict::DiffersFromUnconfirmed { recorded: entry.answer.clone() }
+        }
+    }
+}
+
+// the body of each day's answer tests: solve one part against {input}.txt in the day's crate
+// and compare with the registry. unsolved parts, inputs that haven't been fetched and empty answer
+// slots are skipped
+pub fn check<S: Solution>(part: u8, input: &str) {
+    let registry = Registry::load(&path(S::YEAR)).unwrap();
+
+    let entry = registry
+        .get(S::DAY, part, input)
+        .unwrap_or_else(|| panic!("no answer recorded for day {} part {part} {input}", S::DAY));
+
+    if entry.answer.is_empty() {
+        eprintln!("skipping day {} part {part}: no {input} answer recorded yet", S::DAY);
+        return
+    }
+
+    let file = crate::day_dir(S::DAY).join(format!("{input}.txt"));
+    if !file.exists() {
+        eprintln!("skipping day {} part {part}: {} hasn't been fetched", S::DAY, file.display());
+        return
+    }
+
+    let parsed = S::load(&file).unwrap_or_else(|error| panic!("{error}"));
+
+    let verdict = match part {
+        1 => judge(Some(entry), &S::part1(&parsed)),
+        _ => judge(Some(entry), &S::part2(&parsed))
+    };
+
+    match verdict {
+        Verdict::Unsolved => eprintln!("skipping day {} part {part}: not solved yet", S::DAY),
+        Verdict::Correct | Verdict::MatchesUnconfirmed => (),
+        verdict => panic!("day {} part {part} {input}: {verdict:?}", S::DAY)
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unsolved;
+
+    fn entry(answer: &str, confirmed: bool) -> Entry {
+        Entry { day: 1, part: 1, input: "input".to_string(), answer: answer.to_string(), confirmed }
+    }
+
+    #[test]
+    fn test_judge() {
+        assert_eq!(Verdict::Correct, judge(Some(&entry("42", true)), &42));
+        assert_eq!(Verdict::Wrong { expected: "42".to_string() }, judge(Some(&entry("42", true)), &41));
+        assert_eq!(Verdict::MatchesUnconfirmed, judge(Some(&entry("42", false)), &42));
+        assert_eq!(Verdict::DiffersFromUnconfirmed { recorded: "42".to_string() },
+                   judge(Some(&entry("42", false)), &41));
+        assert_eq!(Verdict::Unknown, judge(None, &42));
+        assert_eq!(Verdict::Unknown, judge(Some(&entry("", false)), &42));
+        assert_eq!(Verdict::Unsolved, judge(Some(&entry("42", true)), &Unsolved));
+    }
+
+    #[test]
+    fn test_set_replaces_and_sorts() {
+        let mut registry = Registry::default();
+
+        registry.set(Entry { day: 2, part: 1, input: "input".to_string(),  answer: "3".to_string(), confirmed: false });
+        registry.set(Entry { day: 2, part: 1, input: "sample".to_string(), answer: "1".to_string(), confirmed: true });
+        registry.set(Entry { day: 1, part: 2, input: "input".to_string(),  answer: "2".to_string(), confirmed: true });
+        registry.set(Entry { day: 2, part: 1, input: "input".to_string(),  answer: "4".to_string(), confirmed: true });
+
+        let order: Vec<(u8, &str, &str)> =
+            registry.answers
+                    .iter()
+                    .map(|e| (e.day, e.input.as_str(), e.answer.as_str()))
+                    .collect();
+
+        assert_eq!(vec![(1, "input", "2"), (2, "sample", "1"), (2, "input", "4")], order);
+    }
+
+    #[test]
+    fn test_stars() {
+        let mut registry = Registry::default();
+
+        registry.set(Entry { day: 3, part: 1, input: "input".to_string(),  answer: "5".to_string(), confirmed: true });
+        registry.set(Entry { day: 3, part: 2, input: "input".to_string(),  answer: "9".to_string(), confirmed: false });
+        registry.set(Entry { day: 3, part: 2, input: "sample".to_string(), answer: "1".to_string(), confirmed: true });
+        registry.set(Entry { day: 4, part: 1, input: "input".to_string(),  answer: String::new(),   confirmed: true });
+
+        assert_eq!(1, registry.stars(3));
+        assert_eq!(0, registry.stars(4));
+    }
+
+    #[test]
+    fn test_registry_parses() {
+        let registry = Registry::load(&path(crate::YEAR)).unwrap();
+        assert_eq!(Some("3"), registry.get(1, 1, "sample").map(|e| e.answer.as_str()));
+        assert_eq!(Some("Playground"), registry.puzzle(8).map(|p| p.title.as_str()));
+    }
+
+    // saving the registry shouldn't reformat the hand-edited file
+    #[test]
+    fn test_registry_round_trips() {
+        let text     = std::fs::read_to_string(path(crate::YEAR)).unwrap();
+        let registry = Registry::load(&path(crate::YEAR)).unwrap();
+
+        assert_eq!(text, format!("{HEADER}{}", toml::to_string(&registry).unwrap()));
+    }
+}
@@ -0,0 +1,299 @@
+// a cellular automaton over a grid of live and dead cells. a rule says which numbers of live
+// neighbours bring a dead cell to life and which a live cell survives, every other count removing
+// it. a run steps the grid until it stops changing or comes back to a grid it has been before
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::grid::{Grid, EIGHT};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    offsets : Vec<(isize, isize)>,
+
+    // indexed by the number of live neighbours
+    born    : Vec<bool>,
+    survives: Vec<bool>
+}
+
+impl Rule {
+    // neighbours at these offsets (grid::FOUR, grid::EIGHT or any others). until told otherwise
+    // nothing is born and everything survives
+    pub fn new(offsets: &[(isize, isize)]) -> Self {
+        Rule {
+            offsets : offsets.to_vec(),
+            born    : vec![false; offsets.len() + 1],
+            survives: vec![true; offsets.len() + 1]
+        }
+    }
+
+    // Conway's Game of Life, B3/S23
+    pub fn life() -> Self {
+        Rule::new(&EIGHT).born([3]).survives([2, 3])
+    }
+
+    // dead cells with this many live neighbours come to life
+    pub fn born(mut self, counts: impl IntoIterator<Item = usize>) -> Self {
+        set(&mut self.born, counts, true, "born");
+        self
+    }
+
+    // live cells survive with exactly these numbers of live neighbours, and no others
+    pub fn survives(mut self, counts: impl IntoIterator<Item = usize>) -> Self {
+        self.survives.fill(false);
+        set(&mut self.survives, counts, true, "survives");
+        self
+    }
+
+    // live cells with this many live neighbours are removed, whatever else survives
+    pub fn removes(mut self, counts: impl IntoIterator<Item = usize>) -> Self {
+        set(&mut self.survives, counts, false, "removes");
+        self
+    }
+
+    fn next(&self, alive: bool, live_neighbours: usize) -> bool {
+        match alive {
+            true  => self.survives[live_neighbours],
+            false => self.born[live_neighbours]
+        }
+    }
+}
+
+fn set(table: &mut [bool], counts: impl IntoIterator<Item = usize>, value: bool, what: &str) {
+    for count in counts {
+        assert!(count < table.len(), "{what}({count}) but a cell only has {} neighbours", table.len() - 1);
+        table[count] = value;
+    }
+}
+
+// what's beyond the edge of the grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edges {
+    Bounded,    // nothing, so cells on the edge have fewer neighbours
+    Wrap        // the other side, as on a torus
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Update {
+    Sync,       // every cell's next state comes from the grid as it was before the step
+    Async       // cells are updated one at a time, row by row, each seeing the ones before it
+}
+
+#[derive(Clone, Debug)]
+pub struct Automaton {
+    pub rule  : Rule,
+    pub edges : Edges,
+    pub update: Update
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    FixedPoint,
+
+    // the grid after `start` steps comes back every `period` steps
+    Cycle { start: usize, period: usize },
+
+    StepLimit
+}
+
+pub struct Run {
+    pub grid   : Grid<bool>,
+    pub steps  : usize,     // the steps that changed the grid
+    pub outcome: Outcome
+}
+
+impl Automaton {
+    // bounded and synchronous unless changed
+    pub fn new(rule: Rule) -> Self {
+        Automaton { rule, edges: Edges::Bounded, update: Update::Sync }
+    }
+
+    pub fn edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    pub fn update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    fn live_neighbours(&self, grid: &Grid<bool>, (row, col): (usize, usize)) -> usize {
+        let (height, width) = (grid.height() as isize, grid.width() as isize);
+
+        self.rule.offsets
+            .iter()
+            .filter(|&&offset| match self.edges {
+                Edges::Bounded => grid.offset((row, col), offset).is_some_and(|coord| grid[coord]),
+                Edges::Wrap    => {
+                    let r = (row as isize + offset.0).rem_euclid(height) as usize;
+                    let c = (col as isize + offset.1).rem_euclid(width) as usize;
+                    grid[(r, c)]
+                }
+            })
+            .count()
+    }
+
+    // one step of the whole grid. false if nothing changed
+    pub fn step(&self, grid: &mut Grid<bool>) -> bool {
+        let mut changed = false;
+
+        match self.update {
+            Update::Sync => {
+                let mut next = grid.clone();
+
+                for (coord, &alive) in grid.iter() {
+                    next[coord] = self.rule.next(alive, self.live_neighbours(grid, coord));
+                    changed |= next[coord] != alive;
+                }
+
+                *grid = next;
+            },
+
+            Update::Async => {
+                for row in 0..grid.height() {
+                    for col in 0..grid.width() {
+                        let alive = grid[(row, col)];
+
+                        grid[(row, col)] = self.rule.next(alive, self.live_neighbours(grid, (row, col)));
+                        changed |= grid[(row, col)] != alive;
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    // step until the grid stops changing, repeats itself, or `limit` steps have gone by
+    pub fn run(&self, grid: &Grid<bool>, limit: usize) -> Run {
+        let mut current = grid.clone();
+
+        // the steps after which the grid had each fingerprint. the same fingerprint is only a
+        // candidate for a cycle, it's confirmed by stepping the first grid there again, so only
+        // the fingerprints need keeping however long the run
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::from([(fingerprint(grid), vec![0])]);
+
+        for step in 1..=limit {
+            if !self.step(&mut current) {
+                return Run { grid: current, steps: step - 1, outcome: Outcome::FixedPoint }
+            }
+
+            let earlier = seen.entry(fingerprint(&current)).or_default();
+
+            if let Some(&start) = earlier.iter().find(|&&start| self.replay(grid, start) == current) {
+                return Run { grid: current, steps: step, outcome: Outcome::Cycle { start, period: step - start } }
+            }
+
+            earlier.push(step);
+        }
+
+        Run { grid: current, steps: limit, outcome: Outcome::StepLimit }
+    }
+
+    fn replay(&self, grid: &Grid<bool>, steps: usize) -> Grid<bool> {
+        let mut grid = grid.clone();
+
+        for _ in 0..steps {
+            self.step(&mut grid);
+        }
+
+        grid
+    }
+}
+
+fn fingerprint(grid: &Grid<bool>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    for row in grid.rows() {
+        row.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::FOUR;
+
+    fn grid(text: &str) -> Grid<bool> {
+        Grid::parse(text, |c| Some(c == '#'), "").unwrap()
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = Rule::new(&FOUR).born([2]).removes([0, 1]);
+
+        assert_eq!(vec![false, false, true, false, false], rule.born);
+        assert_eq!(vec![false, false, true, true, true], rule.survives);
+
+        assert!(rule.next(false, 2) && !rule.next(false, 3));
+        assert!(rule.next(true, 4) && !rule.next(true, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "born(5) but a cell only has 4 neighbours")]
+    fn test_rule_counts_past_the_neighbours() {
+        Rule::new(&FOUR).born([5]);
+    }
+
+    #[test]
+    fn test_blinker() {
+        let life   = Automaton::new(Rule::life());
+        let across = grid(".....\n.....\n.###.\n.....\n.....\n");
+        let down   = grid(".....\n..#..\n..#..\n..#..\n.....\n");
+
+        let mut step = across.clone();
+        assert!(life.step(&mut step));
+        assert_eq!(down, step);
+
+        let run = life.run(&across, 100);
+        assert_eq!(Outcome::Cycle { start: 0, period: 2 }, run.outcome);
+        assert_eq!((2, across), (run.steps, run.grid));
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let life = Automaton::new(Rule::life());
+
+        // a lone cell dies and leaves nothing
+        let run = life.run(&grid("...\n.#.\n...\n"), 100);
+        assert_eq!((Outcome::FixedPoint, 1), (run.outcome, run.steps));
+        assert_eq!(0, run.grid.count(|&alive| alive));
+
+        assert_eq!(Outcome::StepLimit, life.run(&grid(".....\n.###.\n.....\n"), 1).outcome);
+    }
+
+    #[test]
+    fn test_glider_wraps() {
+        let glider = grid(".#....\n..#...\n###...\n......\n......\n......\n");
+
+        // on a torus the glider is back where it started after crossing it, 4 steps a cell
+        let run = Automaton::new(Rule::life()).edges(Edges::Wrap).run(&glider, 100);
+        assert_eq!(Outcome::Cycle { start: 0, period: 24 }, run.outcome);
+
+        // against the edges it turns into a block that never changes
+        let run = Automaton::new(Rule::life()).run(&glider, 100);
+        assert_eq!(Outcome::FixedPoint, run.outcome);
+        assert_eq!(4, run.grid.count(|&alive| alive));
+    }
+
+    #[test]
+    fn test_async() {
+        // a row of cells that die with fewer than 2 neighbours. all at once only the ends go each
+        // step, one at a time the whole row goes in one sweep from the left
+        let rule = Rule::new(&[(0, -1), (0, 1)]).removes([0, 1]);
+        let row  = grid("#####\n");
+
+        let sync = Automaton::new(rule.clone()).run(&row, 100);
+        assert_eq!((Outcome::FixedPoint, 3), (sync.outcome, sync.steps));
+
+        let sweep = Automaton::new(rule).update(Update::Async).run(&row, 100);
+        assert_eq!((Outcome::FixedPoint, 1), (sweep.outcome, sweep.steps));
+        assert_eq!(sync.grid, sweep.grid);
+    }
+}
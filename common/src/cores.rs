@@ -0,0 +1,209 @@
+// k-core decomposition: peel off every node with fewer than k neighbours, again and again until
+// none are left to peel, and what remains is the k-core. a node's core number is the biggest k
+// whose core it's in, so one decomposition answers the question for every threshold at once
+
+use crate::grid::Grid;
+
+// what the decomposition found out about one node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Core {
+    pub degree: usize,      // neighbours before anything was peeled
+    pub core  : usize
+}
+
+impl Core {
+    // whether the node survives peeling everything with fewer than k neighbours
+    pub fn in_k_core(&self, k: usize) -> bool {
+        self.core >= k
+    }
+}
+
+// the core numbers of the nodes 0..nodes of an undirected graph, by Batagelj and Zaversnik's
+// bucket method: take the nodes in order of their current degree, and each one taken lowers the
+// degree of its neighbours that are still waiting, down to its own. O(nodes + edges)
+pub fn cores<I>(nodes: usize, neighbours: impl Fn(usize) -> I) -> Vec<Core>
+where
+    I: IntoIterator<Item = usize>
+{
+    // every node's neighbours one after another, asking for each node's only once
+    let mut edges = vec![];
+    let mut ends  = Vec::with_capacity(nodes);
+
+    for node in 0..nodes {
+        edges.extend(neighbours(node));
+        ends.push(edges.len());
+    }
+
+    let begin = |node: usize| if node == 0 { 0 } else { ends[node - 1] };
+
+    let degrees: Vec<usize> = (0..nodes).map(|node| ends[node] - begin(node)).collect();
+    let max = degrees.iter().copied().max().unwrap_or(0);
+
+    // the nodes sorted by degree, where each degree's bucket starts in that order, and where each
+    // node is in it
+    let mut starts = vec![0; max + 1];
+    for &degree in &degrees {
+        starts[degree] += 1;
+    }
+
+    let mut start = 0;
+    for bucket in starts.iter_mut() {
+        (*bucket, start) = (start, start + *bucket);
+    }
+
+    let mut order    = vec![0; nodes];
+    let mut position = vec![0; nodes];
+    let mut next     = starts.clone();
+
+    for (node, &degree) in degrees.iter().enumerate() {
+        position[node] = next[degree];
+        order[next[degree]] = node;
+        next[degree] += 1;
+    }
+
+    let mut current = degrees.clone();
+
+    for i in 0..nodes {
+        let node = order[i];
+
+        for &neighbour in &edges[begin(node)..ends[node]] {
+            if current[neighbour] > current[node] {
+                // move the neighbour to the front of its bucket, then shrink the bucket past it so
+                // it's at the end of the bucket below
+                let degree = current[neighbour];
+                let front  = order[starts[degree]];
+
+                if front != neighbour {
+                    order.swap(position[front], position[neighbour]);
+                    position.swap(front, neighbour);
+                }
+
+                starts[degree] += 1;
+                current[neighbour] -= 1;
+            }
+        }
+    }
+
+    degrees.into_iter()
+           .zip(current)
+           .map(|(degree, core)| Core { degree, core })
+           .collect()
+}
+
+// the same for the cells of a grid that `is_node` picks out, each one's neighbours being the
+// other such cells at these offsets (grid::FOUR, grid::EIGHT or any others). the graph has to be
+// undirected, so every offset needs its opposite in there too. cells that aren't nodes get None
+pub fn grid_cores<T>(grid   : &Grid<T>,
+                     is_node: impl Fn(&T) -> bool,
+                     offsets: &[(isize, isize)]) -> Grid<Option<Core>>
+{
+    if let Some((dr, dc)) = offsets.iter().find(|&&(dr, dc)| !offsets.contains(&(-dr, -dc))) {
+        panic!("offset ({dr}, {dc}) has no opposite ({}, {}) to make the graph undirected", -dr, -dc)
+    }
+
+    let width = grid.width();
+    let nodes = grid.map(&is_node);
+
+    // cells that aren't nodes are left out of the graph altogether, with no edges either way
+    let neighbours = |node: usize| {
+        let coord = (node / width, node % width);
+
+        grid.neighbours(coord.0, coord.1, if nodes[coord] { offsets } else { &[] })
+            .filter(|&coord| nodes[coord])
+            .map(|(row, col)| row * width + col)
+    };
+
+    let cores = cores(width * grid.height(), neighbours);
+
+    let mut cells = cores.into_iter();
+    nodes.map(|&is_node| {
+        let core = cells.next().unwrap();
+        is_node.then_some(core)
+    })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{EIGHT, FOUR};
+    use crate::random::Lcg;
+
+    #[test]
+    fn test_cores() {
+        // a triangle 0-1-2 with 3 hanging off it, and 4 on its own
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3)];
+        let neighbours = |node: usize| edges.iter()
+            .filter_map(move |&(a, b)| match node {
+                _ if node == a => Some(b),
+                _ if node == b => Some(a),
+                _              => None
+            });
+
+        let cores = cores(5, neighbours);
+
+        assert_eq!(vec![2, 2, 2, 1, 0], cores.iter().map(|core| core.core).collect::<Vec<_>>());
+        assert_eq!(vec![2, 2, 3, 1, 0], cores.iter().map(|core| core.degree).collect::<Vec<_>>());
+        assert!(cores[2].in_k_core(2) && !cores[3].in_k_core(2));
+    }
+
+    #[test]
+    fn test_grid_cores() {
+        let grid = Grid::parse_bytes("###.\n###.\n###.\n...#\n", |_| true, "").unwrap();
+
+        let four = grid_cores(&grid, |&cell| cell == b'#', &FOUR);
+        assert_eq!(None, four[(0, 3)]);
+        assert_eq!(Some(Core { degree: 4, core: 2 }), four[(1, 1)]);
+        assert_eq!(Some(Core { degree: 0, core: 0 }), four[(3, 3)]);
+
+        // diagonally the lone cell touches the block
+        let eight = grid_cores(&grid, |&cell| cell == b'#', &EIGHT);
+        assert_eq!(Some(Core { degree: 8, core: 3 }), eight[(1, 1)]);
+        assert_eq!(Some(Core { degree: 1, core: 1 }), eight[(3, 3)]);
+
+        // only left and right: each row of the block is a path
+        let sideways = grid_cores(&grid, |&cell| cell == b'#', &[(0, -1), (0, 1)]);
+        assert_eq!(Some(Core { degree: 2, core: 1 }), sideways[(2, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset (0, 1) has no opposite (0, -1)")]
+    fn test_grid_cores_one_way() {
+        let grid = Grid::new(3, 3, true);
+        grid_cores(&grid, |&cell| cell, &[(-1, 0), (1, 0), (0, 1)]);
+    }
+
+    // peel a random grid one k at a time, the slow obvious way, and compare
+    #[test]
+    fn test_grid_cores_match_peeling() {
+        let mut random = Lcg::new(12345);
+
+        let cells = (0..30 * 30).map(|_| random.below(10) < 6).collect();
+        let grid  = Grid::from_vec(30, 30, cells).unwrap();
+        let cores = grid_cores(&grid, |&cell| cell, &EIGHT);
+
+        for k in 0..=8 {
+            let mut left = grid.clone();
+
+            loop {
+                let peel: Vec<(usize, usize)> = left.iter()
+                    .filter(|&((row, col), &cell)| cell && left.neighbours8(row, col).filter(|&n| left[n]).count() < k)
+                    .map(|(coord, _)| coord)
+                    .collect();
+
+                if peel.is_empty() {
+                    break
+                }
+                for coord in peel {
+                    left[coord] = false;
+                }
+            }
+
+            for (coord, &cell) in left.iter() {
+                assert_eq!(cell, cores[coord].is_some_and(|core| core.in_k_core(k)), "{coord:?} for k = {k}");
+            }
+        }
+    }
+}
@@ -0,0 +1,350 @@
+// a rectangular grid stored row by row in one Vec. coordinates are (row, col) like the days
+// that used Vec<Vec<_>> before it, with (0, 0) in the top left
+
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Source};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells : Vec<T>,
+    width : usize,
+    height: usize
+}
+
+// (row, col) offsets to the neighbours of a cell, clockwise from the top
+pub const FOUR : [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+pub const EIGHT: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone
+    {
+        Grid { cells: vec![fill; width * height], width, height }
+    }
+
+    // None unless there are exactly width * height cells
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height)
+            .then_some(Grid { cells, width, height })
+    }
+
+    // None if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width  = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None
+        }
+
+        Grid::from_vec(width, height, rows.into_iter().flatten().collect())
+    }
+
+    // each char mapped to a cell, one line per row. `expected` describes the chars `to_cell`
+    // accepts, for the error when it doesn't
+    pub fn parse(text: &str, to_cell: impl FnMut(char) -> Option<T>, expected: &str) -> Result<Self, ParseError> {
+        Grid::parse_lines(&Source::new(text), text.lines(), to_cell, expected)
+    }
+
+    // the same for some of the lines of a bigger text, so errors point into the whole thing
+    pub fn parse_lines<'a>(source  : &Source,
+                           lines   : impl IntoIterator<Item = &'a str>,
+                           mut to_cell: impl FnMut(char) -> Option<T>,
+                           expected: &str) -> Result<Self, ParseError>
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines {
+            let line = line.trim_end_matches('\r');
+
+            if *width.get_or_insert(line.chars().count()) != line.chars().count() {
+                return Err(source.error(line, format!("a row {} wide", width.unwrap())))
+            }
+
+            for (i, c) in line.char_indices() {
+                match to_cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None       => return Err(source.error(&line[i..i + c.len_utf8()], expected))
+                }
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid { cells, width: width.unwrap_or(0), height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.height && col < self.width)
+            .then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        (row < self.height && col < self.width)
+            .then(|| &mut self.cells[row * self.width + col])
+    }
+
+    // the cell at an offset from (row, col), if that's still on the grid
+    pub fn offset(&self, (row, col): (usize, usize), (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+
+        (row < self.height && col < self.width)
+            .then_some((row, col))
+    }
+
+    // the coordinates of the neighbours at these offsets that are on the grid
+    pub fn neighbours<'a>(&'a self, row: usize, col: usize, offsets: &'a [(isize, isize)])
+        -> impl Iterator<Item = (usize, usize)> + 'a
+    {
+        offsets.iter()
+               .filter_map(move |&offset| self.offset((row, col), offset))
+    }
+
+    pub fn neighbours4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(row, col, &FOUR)
+    }
+
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(row, col, &EIGHT)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() won't take a zero width
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |row| &self[(row, col)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    // every cell with its coordinates, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i / self.width, i % self.width), cell))
+    }
+
+    // the first cell, row by row, that satisfies the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq
+    {
+        self.position(|cell| cell == value)
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.cells
+            .iter()
+            .filter(|cell| predicate(cell))
+            .count()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells : self.cells.iter().map(f).collect(),
+            width : self.width,
+            height: self.height
+        }
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone
+    {
+        Grid {
+            cells : self.columns().flat_map(|column| column.cloned()).collect(),
+            width : self.height,
+            height: self.width
+        }
+    }
+
+    // a quarter turn clockwise: the bottom row becomes the first column
+    pub fn rotate(&self) -> Grid<T>
+    where
+        T: Clone
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| (row, col)))
+            .map(|coord| self[coord].clone())
+            .collect();
+
+        Grid { cells, width: self.height, height: self.width }
+    }
+}
+
+// a grid of the input's bytes, for the days whose cells are all ASCII. each line is copied in
+// whole, leaving its line ending behind, so there's no per-cell work
+impl Grid<u8> {
+    pub fn parse_bytes(text: &str, valid: impl Fn(u8) -> bool, expected: &str) -> Result<Self, ParseError> {
+        let source = Source::new(text);
+
+        let width  = text.lines().next().map_or(0, |line| line.trim_end_matches('\r').len());
+        let height = text.lines().count();
+
+        let mut cells = Vec::with_capacity(height * width);
+
+        for line in text.lines() {
+            let row = line.trim_end_matches('\r');
+
+            if row.len() != width {
+                return Err(source.error(row, format!("a row {width} wide")))
+            }
+
+            // checking every byte without stopping at the first bad one avoids a branch per byte,
+            // which a random mix of cells keeps mispredicting
+            if !row.bytes().fold(true, |ok, byte| ok & valid(byte)) {
+                let i = row.bytes().position(|byte| !valid(byte)).unwrap();
+
+                // the whole char the bad byte belongs to, so the error can show it
+                let (at, c) = row.char_indices().take_while(|&(at, _)| at <= i).last().unwrap();
+                return Err(source.error(&row[at..at + c.len_utf8()], expected))
+            }
+
+            cells.extend_from_slice(row.as_bytes());
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+}
+
+// unchecked in the sense that going off the grid panics, like indexing a Vec
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "column {col} is off a grid {} wide", self.width);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "column {col} is off a grid {} wide", self.width);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some, "anything").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!('f', grid[(1, 2)]);
+        assert_eq!(Some(&'d'), grid.get(1, 0));
+        assert_eq!(None, grid.get(2, 0));
+        assert_eq!(None, grid.get(0, 3));
+
+        let error = Grid::parse("ab\nabc\n", Some, "anything").unwrap_err();
+        assert_eq!((2, 1), (error.line, error.column));
+
+        let error = Grid::parse("..\n.x\n", |c| (c == '.').then_some(0), "a dot").unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+
+        assert_eq!(vec![(0, 1), (1, 0)], grid.neighbours4(0, 0).collect::<Vec<_>>());
+        assert_eq!(5, grid.neighbours8(1, 1).count());
+        assert_eq!(vec![(1, 1)], grid.neighbours(0, 0, &[(1, 1), (-1, -1)]).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!(vec!["abc".to_string(), "def".to_string()],
+                   grid.rows().map(|row| row.iter().collect()).collect::<Vec<String>>());
+
+        assert_eq!(vec!["ad".to_string(), "be".to_string(), "cf".to_string()],
+                   grid.columns().map(|col| col.collect()).collect::<Vec<String>>());
+    }
+
+    #[test]
+    fn test_position() {
+        let grid = sample();
+
+        assert_eq!(Some((1, 1)), grid.position_of(&'e'));
+        assert_eq!(Some((0, 2)), grid.position(|&c| c > 'b'));
+        assert_eq!(None, grid.position_of(&'z'));
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = sample();
+
+        assert_eq!(Grid::parse("ad\nbe\ncf", Some, "").unwrap(), grid.transpose());
+        assert_eq!(Grid::parse("da\neb\nfc", Some, "").unwrap(), grid.rotate());
+        assert_eq!(grid, grid.rotate().rotate().rotate().rotate());
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let grid = Grid::parse_bytes("abc\r\ndef\r\n", |b| b.is_ascii_lowercase(), "a letter").unwrap();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(b"def", grid.row(1));
+        assert_eq!(b'c', grid[(0, 2)]);
+        assert_eq!(None, grid.get(0, 3));
+        assert_eq!(Some((1, 0)), grid.position_of(&b'd'));
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2), (1, 0)], grid.iter().take(4).map(|(coord, _)| coord).collect::<Vec<_>>());
+
+        // the same cells as the char grid, whatever the line endings
+        assert_eq!(sample().map(|&c| c as u8), grid);
+        assert_eq!(grid, Grid::parse_bytes("abc\ndef", |_| true, "").unwrap());
+
+        let error = Grid::parse_bytes("ab\nabc\n", |_| true, "anything").unwrap_err();
+        assert_eq!((2, 1), (error.line, error.column));
+
+        let error = Grid::parse_bytes("...\n.é\n", |b| b == b'.', "a dot").unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+        assert_eq!("é", error.found);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is off a grid 3 wide")]
+    fn test_index_off_the_grid() {
+        let _ = sample()[(0, 3)];
+    }
+}
@@ -0,0 +1,242 @@
+// the log of answers we've submitted for the real input and what adventofcode.com said about
+// them, one TOML file per year next to the answer registry. from the too high/too low feedback
+// we can work out the open interval the real answer has to be in, and catch a bad answer
+// before it costs us another submission cooldown
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::RegistryError;
+
+const HEADER: &str = "\
+# Every answer submitted for the real input, and the feedback it got.
+#
+#   feedback = correct, too-high, too-low, or wrong (rejected without a hint)
+
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow  => write!(f, "too low"),
+            Feedback::Wrong   => write!(f, "wrong")
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct"  => Ok(Feedback::Correct),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low"  => Ok(Feedback::TooLow),
+            "wrong"    => Ok(Feedback::Wrong),
+            _          => Err(format!("expected correct, too-high, too-low or wrong, got \"{s}\""))
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day     : u8,
+    pub part    : u8,
+    pub answer  : String,
+    pub feedback: Feedback
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GuessLog {
+    #[serde(default, rename = "guess")]
+    pub guesses: Vec<Guess>
+}
+
+// the open interval (above, below) a numeric answer must fall in. None means unbounded
+#[derive(Debug, Default, PartialEq)]
+pub struct Bounds {
+    pub above: Option<i128>,
+    pub below: Option<i128>
+}
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.above, self.below) {
+            (Some(above), Some(below)) => write!(f, "{above} < answer < {below}"),
+            (Some(above), None)        => write!(f, "answer > {above}"),
+            (None,        Some(below)) => write!(f, "answer < {below}"),
+            (None,        None)        => write!(f, "no bounds yet")
+        }
+    }
+}
+
+// why a freshly computed answer shouldn't be submitted
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    AlreadyRejected(Feedback),
+    TooHigh { below: i128 },
+    TooLow { above: i128 }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::AlreadyRejected(feedback) => write!(f, "already rejected as {feedback}"),
+            Problem::TooHigh { below }         => write!(f, "too high, the answer is below {below}"),
+            Problem::TooLow { above }          => write!(f, "too low, the answer is above {above}")
+        }
+    }
+}
+
+// answers/{year}-guesses.toml at the root of the workspace
+pub fn path(year: u16) -> PathBuf {
+    crate::root()
+        .join("answers")
+        .join(format!("{year}-guesses.toml"))
+}
+
+impl GuessLog {
+    // a year with no guesses yet has no file, which is the same as an empty log
+    pub fn load(path: &Path) -> Result<GuessLog, RegistryError> {
+        if !path.exists() {
+            return Ok(GuessLog::default())
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| RegistryError::Io { path: path.to_path_buf(), error })?;
+
+        toml::from_str(&text)
+            .map_err(|error| RegistryError::Toml { path: path.to_path_buf(), error })
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let toml = toml::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, format!("{HEADER}{toml}"))
+    }
+
+    // guesses stay in the order they were made
+    pub fn record(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+
+    pub fn for_part(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    // the tightest interval the too high/too low feedback allows
+    pub fn bounds(&self, day: u8, part: u8) -> Bounds {
+        let mut bounds = Bounds::default();
+
+        for guess in self.for_part(day, part) {
+            let Ok(value) = guess.answer.parse::<i128>() else { continue };
+
+            match guess.feedback {
+                Feedback::TooLow  => bounds.above = bounds.above.max(Some(value)),
+                Feedback::TooHigh => bounds.below = Some(bounds.below.map_or(value, |b| b.min(value))),
+                _ => ()
+            }
+        }
+
+        bounds
+    }
+
+    // check a candidate answer against everything we've been told so far
+    pub fn assess(&self, day: u8, part: u8, answer: &str) -> Option<Problem> {
+        let rejected = self.for_part(day, part)
+                           .find(|guess| guess.answer == answer && guess.feedback != Feedback::Correct);
+
+        if let Some(guess) = rejected {
+            return Some(Problem::AlreadyRejected(guess.feedback))
+        }
+
+        // answers that aren't numbers can't be compared against the bounds
+        let value  = answer.parse::<i128>().ok()?;
+        let bounds = self.bounds(day, part);
+
+        match (bounds.above, bounds.below) {
+            (_, Some(below)) if value >= below => Some(Problem::TooHigh { below }),
+            (Some(above), _) if value <= above => Some(Problem::TooLow { above }),
+            _ => None
+        }
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(answer: &str, feedback: Feedback) -> Guess {
+        Guess { day: 1, part: 2, answer: answer.to_string(), feedback }
+    }
+
+    // day 1 part 2, as it happened
+    fn day01() -> GuessLog {
+        GuessLog {
+            guesses: vec![ guess("7937", Feedback::TooHigh),
+                           guess("6777", Feedback::TooHigh),
+                           guess("4711", Feedback::TooLow),
+                           guess("6689", Feedback::Correct) ]
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds { above: Some(4711), below: Some(6777) }, day01().bounds(1, 2));
+        assert_eq!(Bounds::default(), day01().bounds(1, 1));
+        assert_eq!("4711 < answer < 6777", day01().bounds(1, 2).to_string());
+    }
+
+    #[test]
+    fn test_assess() {
+        let log = day01();
+
+        assert_eq!(None, log.assess(1, 2, "6689"));
+        assert_eq!(None, log.assess(1, 2, "4712"));
+        assert_eq!(Some(Problem::AlreadyRejected(Feedback::TooHigh)), log.assess(1, 2, "7937"));
+        assert_eq!(Some(Problem::AlreadyRejected(Feedback::TooHigh)), log.assess(1, 2, "6777"));
+        assert_eq!(Some(Problem::TooHigh { below: 6777 }), log.assess(1, 2, "6778"));
+        assert_eq!(Some(Problem::TooHigh { below: 6777 }), log.assess(1, 2, "9999"));
+        assert_eq!(Some(Problem::TooLow { above: 4711 }), log.assess(1, 2, "100"));
+
+        // other parts and non-numeric answers don't get caught by these bounds
+        assert_eq!(None, log.assess(1, 1, "9999"));
+        assert_eq!(None, log.assess(1, 2, "abc"));
+    }
+
+    #[test]
+    fn test_wrong_without_a_hint() {
+        let log = GuessLog { guesses: vec![guess("123", Feedback::Wrong)] };
+
+        assert_eq!(Some(Problem::AlreadyRejected(Feedback::Wrong)), log.assess(1, 2, "123"));
+        assert_eq!(Bounds::default(), log.bounds(1, 2));
+    }
+
+    #[test]
+    fn test_guess_log_parses() {
+        let log = GuessLog::load(&path(2025)).unwrap();
+        assert_eq!(Bounds { above: Some(4711), below: Some(6777) }, log.bounds(1, 2));
+    }
+}
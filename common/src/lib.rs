@@ -0,0 +1,104 @@
+// code shared between the days and the runner
+
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+pub mod answers;
+pub mod automaton;
+pub mod cores;
+pub mod grid;
+pub mod guesses;
+pub mod parse;
+pub mod polygon;
+pub mod random;
+pub mod render;
+
+use grid::Grid;
+use parse::{LoadError, ParseError};
+use render::Palette;
+
+// the year this repo is solving
+pub const YEAR: u16 = 2025;
+
+// the root of the workspace, or wherever AOC_ROOT points instead
+pub fn root() -> PathBuf {
+    match std::env::var_os("AOC_ROOT") {
+        Some(root) => PathBuf::from(root),
+        None       => Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+    }
+}
+
+// a day's crate, where its sample and input files live
+pub fn day_dir(day: u8) -> PathBuf {
+    root().join(format!("day{day:02}"))
+}
+
+// every day implements this so the runner, benches and tests can treat them all the same way
+pub trait Solution {
+
+    // the day of the month, used to find this day's entries in the answer registry
+    const DAY: u8;
+
+    // and the year, for which registry to look in
+    const YEAR: u16 = YEAR;
+
+    // the parsed puzzle input
+    type Input;
+
+    // fails with the line and column of whatever doesn't look like this day's input
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Answer;
+    fn part2(input: &Self::Input) -> impl Answer;
+
+    // both answers at once. days that compute them together (day 6) can override this to
+    // do the work only once
+    fn parts(input: &Self::Input) -> (impl Answer, impl Answer) {
+        (Self::part1(input), Self::part2(input))
+    }
+
+    // the states a day's simulation goes through, for `aoc animate`. most days don't have one
+    fn frames(_input: &Self::Input) -> Vec<Grid<u8>> {
+        vec![]
+    }
+
+    // the colour of each tile in those frames
+    fn palette() -> Palette {
+        Palette::default()
+    }
+
+    // read and parse a file
+    fn load(path: impl AsRef<Path>) -> Result<Self::Input, LoadError> {
+        let path = path.as_ref();
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| LoadError::Io { path: path.to_path_buf(), error })?;
+
+        Self::parse(&text)
+            .map_err(|error| LoadError::Parse(error.in_file(path)))
+    }
+}
+
+// anything a part can return. a part that hasn't been solved yet returns Unsolved, so nobody
+// mistakes a placeholder for a real answer
+pub trait Answer: Display {
+    fn is_solved(&self) -> bool { true }
+}
+
+macro_rules! answer {
+    ($($t:ty),*) => { $(impl Answer for $t {})* };
+}
+
+answer!(i32, i64, u32, u64, usize, String, &str);
+
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+impl Answer for Unsolved {
+    fn is_solved(&self) -> bool { false }
+}
@@ -0,0 +1,190 @@
+// errors for puzzle inputs that don't look the way a parser expects, pointing at the line and
+// column of the offending text so a truncated input or the wrong day's file is easy to spot
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub file    : Option<PathBuf>,
+    pub line    : usize,
+    pub column  : usize,
+
+    // the text that didn't parse, empty if there was nothing there at all
+    pub found   : String,
+    pub expected: String,
+
+    // the whole line the error is on, to show it in context
+    pub context : String
+}
+
+impl ParseError {
+    // parsers don't know which file their text came from, whoever read it fills that in
+    pub fn in_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.file = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+
+        write!(f, "{}:{}: expected {}, ", self.line, self.column, self.expected)?;
+
+        match self.found.as_str() {
+            ""    => write!(f, "found nothing")?,
+            found => write!(f, "found \"{}\"", shorten(found))?
+        }
+
+        // the line itself with a caret under the column
+        if !self.context.is_empty() {
+            let context = shorten(&self.context);
+            let indent  = (self.column - 1).min(context.chars().count());
+
+            write!(f, "\n    {context}\n    {}^", " ".repeat(indent))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+fn shorten(text: &str) -> String {
+    const MAX: usize = 60;
+
+    match text.char_indices().nth(MAX) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None           => text.to_string()
+    }
+}
+
+// the whole text being parsed, so errors about any slice of it can say where that slice is
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    text: &'a str
+}
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Source { text }
+    }
+
+    // an error about `found`, which should be a slice of the source text (from lines(),
+    // split_once() and so on) so its position can be worked out
+    pub fn error(&self, found: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let at    = found.as_ptr() as usize;
+
+        let offset = if (start..=start + self.text.len()).contains(&at) {
+            at - start
+        } else {
+            self.text.find(found).unwrap_or(self.text.len())
+        };
+
+        self.error_at(offset, found, expected)
+    }
+
+    // the text ran out before something we needed
+    pub fn end(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text.len(), "", expected)
+    }
+
+    fn error_at(&self, offset: usize, found: &str, expected: impl Into<String>) -> ParseError {
+        let before     = &self.text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end   = self.text[offset..].find('\n').map_or(self.text.len(), |i| offset + i);
+
+        ParseError {
+            file    : None,
+            line    : before.matches('\n').count() + 1,
+            column  : before[line_start..].chars().count() + 1,
+            found   : found.to_string(),
+            expected: expected.into(),
+            context : self.text[line_start..line_end].trim_end_matches('\r').to_string()
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, text: &str, expected: &str) -> Result<T, ParseError> {
+        text.parse()
+            .map_err(|_| self.error(text, expected))
+    }
+
+    pub fn split_once(&self, text: &'a str, separator: &str, expected: &str)
+        -> Result<(&'a str, &'a str), ParseError>
+    {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(text, expected))
+    }
+}
+
+// reading an input file can fail before we ever get to parse it
+#[derive(Debug)]
+pub enum LoadError {
+    Io { path: PathBuf, error: io::Error },
+    Parse(ParseError)
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io { path, error } => write!(f, "couldn't read {}: {error}", path.display()),
+            LoadError::Parse(error)       => write!(f, "{error}")
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let text   = "L68\nR4x\nX5\n";
+        let source = Source::new(text);
+        let line   = text.lines().nth(2).unwrap();
+
+        let error = source.error(&line[..1], "L or R");
+
+        assert_eq!((3, 1), (error.line, error.column));
+        assert_eq!("X", error.found);
+        assert_eq!("X5", error.context);
+
+        let error = source.parse::<u32>(&text[5..7], "a number").unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_end() {
+        let error = Source::new("1,2\n3,4").end("a blank line");
+
+        assert_eq!((2, 4), (error.line, error.column));
+        assert_eq!("", error.found);
+    }
+
+    #[test]
+    fn test_display() {
+        let text  = "4x4: 0 0\n12y5: 1 0\n";
+        let error = Source::new(text)
+            .split_once(&text[9..13], "x", "a size like 12x5")
+            .unwrap_err()
+            .in_file("day12/input.txt");
+
+        assert_eq!("day12/input.txt:2:1: expected a size like 12x5, found \"12y5\"\n    12y5: 1 0\n    ^",
+                   error.to_string());
+
+        let error = Source::new("").end("a number");
+        assert_eq!("1:1: expected a number, found nothing", error.to_string());
+    }
+}
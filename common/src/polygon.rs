@@ -0,0 +1,297 @@
+// checks that a list of points is a simple closed loop of horizontal and vertical edges, as
+// solvers that shade the inside of a loop assume. point i is taken to be from line i + 1 of the
+// input, and the last point joins back up with the first
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Flaw {
+    // a loop needs at least four corners to go round anything
+    TooFew { count: usize },
+
+    // the same point twice
+    Duplicate { first: usize, again: usize },
+
+    // consecutive points that share neither a row nor a column
+    Diagonal { from: usize, to: usize },
+
+    // the loop comes into a point and goes straight back out the way it came
+    Backtrack { at: usize },
+
+    // two edges that aren't next to each other in the loop touch or cross. edge i runs from
+    // point i to the one after it
+    Crossing { edge1: usize, edge2: usize }
+}
+
+impl Flaw {
+    // the point to blame, the later one where there's a choice
+    pub fn point(&self) -> Option<usize> {
+        match *self {
+            Flaw::TooFew    { .. }        => None,
+            Flaw::Duplicate { again, .. } => Some(again),
+            Flaw::Diagonal  { to, .. }    => Some(to),
+            Flaw::Backtrack { at }        => Some(at),
+            Flaw::Crossing  { edge2, .. } => Some(edge2)
+        }
+    }
+
+    // what should have been at the point instead, to go in a parse error
+    pub fn expected(&self, count: usize) -> String {
+        let next = |i: usize| (i + 1) % count + 1;
+
+        match *self {
+            Flaw::TooFew    { .. }           => "at least 4 points to make a loop".to_string(),
+            Flaw::Duplicate { first, .. }    => format!("a point not already on line {}", first + 1),
+            Flaw::Diagonal  { from, .. }     => format!("a point in the same row or column as line {}", from + 1),
+            Flaw::Backtrack { .. }           => "a point where the loop turns or carries on, not one it doubles back from".to_string(),
+            Flaw::Crossing  { edge1, edge2 } => format!("an edge to line {} that keeps clear of the one from line {} to {}",
+                                                        next(edge2), edge1 + 1, next(edge1))
+        }
+    }
+}
+
+impl fmt::Display for Flaw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Flaw::TooFew    { count }        => write!(f, "only {count} points, a loop needs at least 4"),
+            Flaw::Duplicate { first, again } => write!(f, "line {} repeats line {}", again + 1, first + 1),
+            Flaw::Diagonal  { from, to }     => write!(f, "lines {} and {} share neither a row nor a column", from + 1, to + 1),
+            Flaw::Backtrack { at }           => write!(f, "the loop doubles back on itself at line {}", at + 1),
+            Flaw::Crossing  { edge1, edge2 } => write!(f, "the edge from line {} crosses the edge from line {}", edge2 + 1, edge1 + 1)
+        }
+    }
+}
+
+// every flaw in the loop, in the order of the points they're blamed on (too few points first, and
+// in the order of the kinds above for the same point).
+// edges that go diagonally aren't checked for crossings, there's no telling which way they'd go
+pub fn check<T: Ord + Copy>(points: &[(T, T)]) -> Result<(), Vec<Flaw>> {
+    let count = points.len();
+    let next  = |i: usize| (i + 1) % count;
+    let prev  = |i: usize| (i + count - 1) % count;
+
+    let mut flaws = vec![];
+
+    if count < 4 {
+        flaws.push(Flaw::TooFew { count });
+    }
+
+    // equal points end up next to each other once sorted, the first one seen stays first
+    let mut sorted: Vec<usize> = (0..count).collect();
+    sorted.sort_by_key(|&i| (points[i], i));
+
+    for pair in sorted.windows(2) {
+        if points[pair[0]] == points[pair[1]] {
+            let first = sorted.iter().find(|&&i| points[i] == points[pair[0]]).unwrap();
+            flaws.push(Flaw::Duplicate { first: *first, again: pair[1] });
+        }
+    }
+
+    let in_line = |a: (T, T), b: (T, T)| a.0 == b.0 || a.1 == b.1;
+
+    for from in 0..count {
+        if !in_line(points[from], points[next(from)]) {
+            flaws.push(Flaw::Diagonal { from, to: next(from) });
+        }
+    }
+
+    // coming in along a line and going back out along it. a repeated point is already a flaw
+    for at in 0..count {
+        let (a, b, c) = (points[prev(at)], points[at], points[next(at)]);
+
+        if a == b || b == c || !in_line(a, b) || !in_line(b, c) {
+            continue
+        }
+
+        let back = if a.0 == b.0 && b.0 == c.0 { (a.1 < b.1) != (b.1 < c.1) }
+              else if a.1 == b.1 && b.1 == c.1 { (a.0 < b.0) != (b.0 < c.0) }
+              else                             { false };
+
+        if back {
+            flaws.push(Flaw::Backtrack { at });
+        }
+    }
+
+    // edges that aren't next to each other shouldn't meet at all, except where a repeated
+    // point is the end of both, which has been reported already. sorted by where they start
+    // across, each edge only needs comparing with the ones that start before it finishes
+    let ends = |edge: usize| [points[edge], points[next(edge)]];
+    let left = |edge: usize| points[edge].0.min(points[next(edge)].0);
+
+    let mut edges: Vec<usize> = (0..count)
+        .filter(|&i| count > 1 && in_line(points[i], points[next(i)]))
+        .collect();
+
+    edges.sort_by_key(|&edge| left(edge));
+
+    for (j, &edge) in edges.iter().enumerate() {
+        let right = points[edge].0.max(points[next(edge)].0);
+
+        for &other in edges[j+1..].iter().take_while(|&&other| left(other) <= right) {
+            let (edge1, edge2) = (edge.min(other), edge.max(other));
+
+            if next(edge1) == edge2 || next(edge2) == edge1 {
+                continue
+            }
+
+            if let Some(meet) = overlap(ends(edge1), ends(edge2)) {
+                if meet.0 == meet.1 && ends(edge1).contains(&meet.0) && ends(edge2).contains(&meet.0) {
+                    continue
+                }
+
+                flaws.push(Flaw::Crossing { edge1, edge2 });
+            }
+        }
+    }
+
+    flaws.sort_by_key(|&flaw| (flaw.point().map_or(0, |point| point + 1), flaw));
+
+    match flaws.is_empty() {
+        true  => Ok(()),
+        false => Err(flaws)
+    }
+}
+
+// the corners of the box two horizontal or vertical edges have in common, if any
+fn overlap<T: Ord + Copy>(edge1: [(T, T); 2], edge2: [(T, T); 2]) -> Option<((T, T), (T, T))> {
+    let low  = |edge: [(T, T); 2]| (edge[0].0.min(edge[1].0), edge[0].1.min(edge[1].1));
+    let high = |edge: [(T, T); 2]| (edge[0].0.max(edge[1].0), edge[0].1.max(edge[1].1));
+
+    let (low1, high1) = (low(edge1), high(edge1));
+    let (low2, high2) = (low(edge2), high(edge2));
+
+    let from = (low1.0.max(low2.0),   low1.1.max(low2.1));
+    let to   = (high1.0.min(high2.0), high1.1.min(high2.1));
+
+    (from.0 <= to.0 && from.1 <= to.1).then_some((from, to))
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Lcg;
+
+    // the day 9 sample
+    const SAMPLE: [(u32, u32); 8] = [(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)];
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Ok(()), check(&SAMPLE));
+
+        // a corner that carries straight on is fine
+        assert_eq!(Ok(()), check(&[(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)]));
+    }
+
+    #[test]
+    fn test_too_few() {
+        assert_eq!(Err(vec![Flaw::TooFew { count: 0 }]), check::<u32>(&[]));
+        assert_eq!(Err(vec![Flaw::TooFew { count: 2 }, Flaw::Backtrack { at: 0 }, Flaw::Backtrack { at: 1 }]),
+                   check(&[(0, 0), (0, 3)]));
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let mut points = SAMPLE.to_vec();
+        points.insert(4, (11, 1));
+
+        // the repeat also leaves a diagonal on the way to it, and the way out of it
+        assert_eq!(Err(vec![Flaw::Duplicate { first: 1, again: 4 },
+                            Flaw::Diagonal  { from: 3, to: 4 },
+                            Flaw::Diagonal  { from: 4, to: 5 }]),
+                   check(&points));
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let mut points = SAMPLE.to_vec();
+        points.swap(2, 3);
+
+        let flaws = check(&points).unwrap_err();
+
+        assert!(flaws.contains(&Flaw::Diagonal { from: 1, to: 2 }));
+        assert!(flaws.contains(&Flaw::Diagonal { from: 3, to: 4 }));
+
+        // the loop not closing is a diagonal from the last line back to the first
+        let open = [(0, 0), (2, 0), (2, 2), (1, 2), (1, 1)];
+
+        assert_eq!(Err(vec![Flaw::Diagonal { from: 4, to: 0 }]), check(&open));
+        assert_eq!("lines 5 and 1 share neither a row nor a column", check(&open).unwrap_err()[0].to_string());
+    }
+
+    #[test]
+    fn test_backtrack() {
+        // out to (3, 0) then back along the same row past where it came from
+        let points = [(0, 0), (3, 0), (1, 0), (1, 2), (0, 2)];
+        let flaws  = check(&points).unwrap_err();
+
+        assert_eq!(Flaw::Backtrack { at: 1 }, flaws[0]);
+        assert_eq!("the loop doubles back on itself at line 2", flaws[0].to_string());
+    }
+
+    #[test]
+    fn test_crossing() {
+        // a figure of eight, where the edge from line 5 crosses the edge from line 2
+        let points = [(0, 0), (2, 0), (2, 4), (4, 4), (4, 2), (0, 2)];
+
+        assert_eq!(Err(vec![Flaw::Crossing { edge1: 1, edge2: 4 }]), check(&points));
+        assert_eq!("the edge from line 5 crosses the edge from line 2",
+                   Flaw::Crossing { edge1: 1, edge2: 4 }.to_string());
+
+        // running back along part of an earlier edge counts too
+        let points = [(0, 0), (2, 0), (2, 2), (4, 2), (4, 0), (1, 0)];
+
+        assert_eq!(Err(vec![Flaw::Backtrack { at: 0 },
+                            Flaw::Crossing  { edge1: 0, edge2: 4 },
+                            Flaw::Crossing  { edge1: 1, edge2: 4 }]),
+                   check(&points));
+    }
+
+    #[test]
+    fn test_crossings_match_all_pairs() {
+        let mut random = Lcg::new(12345);
+
+        for _ in 0..300 {
+            // a random walk, turning at each point, on a small enough floor to run into itself
+            let mut points = vec![(0, 0)];
+            for i in 0..4 + random.below(12) as usize {
+                let (x, y) = points[i];
+                let step   = random.below(7) as i32 - 3;
+
+                points.push(if i % 2 == 0 { (x + step, y) } else { (x, y + step) });
+            }
+
+            let count = points.len();
+            let next  = |i: usize| (i + 1) % count;
+            let ends  = |edge: usize| [points[edge], points[next(edge)]];
+            let edges = (0..count).filter(|&i| points[i].0 == points[next(i)].0 || points[i].1 == points[next(i)].1);
+
+            let mut expected = vec![];
+            for edge2 in edges.clone() {
+            for edge1 in edges.clone().take_while(|&edge1| edge1 < edge2) {
+                if next(edge1) == edge2 || next(edge2) == edge1 {
+                    continue
+                }
+                if let Some(meet) = overlap(ends(edge1), ends(edge2))
+                    && (meet.0 != meet.1 || !ends(edge1).contains(&meet.0) || !ends(edge2).contains(&meet.0)) {
+                    expected.push(Flaw::Crossing { edge1, edge2 });
+                }
+            }}
+            expected.sort_by_key(|&flaw| (flaw.point(), flaw));
+
+            let flaws   = check(&points).err().unwrap_or_default();
+            let crossed = flaws.into_iter().filter(|flaw| matches!(flaw, Flaw::Crossing { .. })).collect::<Vec<_>>();
+
+            assert_eq!(expected, crossed, "{points:?}");
+        }
+    }
+
+    #[test]
+    fn test_expected() {
+        let flaw = Flaw::Crossing { edge1: 1, edge2: 4 };
+        assert_eq!(Some(4), flaw.point());
+        assert_eq!("an edge to line 6 that keeps clear of the one from line 2 to 3", flaw.expected(6));
+    }
+}
@@ -0,0 +1,51 @@
+// a small seeded random number generator for the tests that check a fast method against a slow
+// obvious one on made-up inputs. it's a 64-bit LCG keeping the high bits, which is plenty for
+// scattering tiles about, and the same seed always gives the same inputs so a failure replays
+
+pub struct Lcg {
+    state: u64
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg { state: seed }
+    }
+
+    // 31 random bits
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.state >> 33) as u32
+    }
+
+    // a number from 0 up to but not including `n`
+    pub fn below(&mut self, n: u32) -> u32 {
+        self.next_u32() % n
+    }
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcg() {
+        let numbers: Vec<u32> = {
+            let mut random = Lcg::new(12345);
+            (0..100).map(|_| random.below(10)).collect()
+        };
+
+        // the same seed, the same numbers
+        let mut again = Lcg::new(12345);
+        assert!(numbers.iter().all(|&n| n == again.below(10)));
+
+        // and all of them get a look in
+        assert!((0..10).all(|n| numbers.contains(&n)));
+
+        // another seed, other numbers
+        let mut other = Lcg::new(54321);
+        assert_ne!(numbers, (0..100).map(|_| other.below(10)).collect::<Vec<_>>());
+    }
+}
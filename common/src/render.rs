@@ -0,0 +1,486 @@
+// pictures of grids, for watching a simulation instead of reading print statements: an animated
+// GIF of every frame, a numbered PNG or PPM for each one, or playback in a terminal. everything is
+// encoded here, PNGs with uncompressed deflate blocks and GIFs with their own LZW
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+// the colour of each tile, by its character. tiles without one get the fallback
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    colours : Vec<(u8, Rgb)>,
+    fallback: Rgb
+}
+
+impl Default for Palette {
+    // magenta, so a tile someone forgot stands out
+    fn default() -> Self {
+        Palette::new([255, 0, 255])
+    }
+}
+
+impl Palette {
+    pub fn new(fallback: Rgb) -> Self {
+        Palette { colours: vec![], fallback }
+    }
+
+    pub fn with(mut self, tile: char, colour: Rgb) -> Self {
+        assert!(tile.is_ascii(), "tiles are single bytes, '{tile}' isn't ASCII");
+        assert!(self.colours.len() < 255, "a palette holds at most 255 tiles");
+
+        self.colours.retain(|&(t, _)| t != tile as u8);
+        self.colours.push((tile as u8, colour));
+        self
+    }
+
+    pub fn colour(&self, tile: u8) -> Rgb {
+        self.colours
+            .iter()
+            .find(|&&(t, _)| t == tile)
+            .map_or(self.fallback, |&(_, colour)| colour)
+    }
+
+    // the tiles' colours in order with the fallback last, which is a GIF's colour table
+    fn table(&self) -> Vec<Rgb> {
+        self.colours
+            .iter()
+            .map(|&(_, colour)| colour)
+            .chain([self.fallback])
+            .collect()
+    }
+
+    fn index(&self, tile: u8) -> u8 {
+        self.colours
+            .iter()
+            .position(|&(t, _)| t == tile)
+            .unwrap_or(self.colours.len()) as u8
+    }
+}
+
+// each tile as a square of scale x scale pixels, row by row
+fn pixels<P>(frame: &Grid<u8>, scale: usize, pixel: impl Fn(u8) -> P) -> impl Iterator<Item = P> {
+    (0..frame.height() * scale).flat_map(move |row| {
+        let tiles = frame.row(row / scale);
+        (0..frame.width() * scale).map(move |col| tiles[col / scale])
+    })
+    .map(pixel)
+}
+
+
+/* Stills */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Still {
+    Png,
+    Ppm
+}
+
+impl Still {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Still::Png => "png",
+            Still::Ppm => "ppm"
+        }
+    }
+
+    pub fn encode(&self, frame: &Grid<u8>, palette: &Palette, scale: usize) -> Vec<u8> {
+        match self {
+            Still::Png => png(frame, palette, scale),
+            Still::Ppm => ppm(frame, palette, scale)
+        }
+    }
+}
+
+// a binary PPM, the simplest image format there is
+pub fn ppm(frame: &Grid<u8>, palette: &Palette, scale: usize) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", frame.width() * scale, frame.height() * scale).into_bytes();
+
+    image.extend(pixels(frame, scale, |tile| palette.colour(tile)).flatten());
+    image
+}
+
+// an 8-bit RGB PNG. its deflate stream is made of stored blocks, which any decoder reads and which
+// need no compressor, and a grid's worth of pixels is small anyway
+pub fn png(frame: &Grid<u8>, palette: &Palette, scale: usize) -> Vec<u8> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+
+    // each row of pixels starts with its filter type, 0 for none
+    let mut raw = Vec::with_capacity(height * (1 + width * 3));
+    let mut colours = pixels(frame, scale, |tile| palette.colour(tile));
+
+    for _ in 0..height {
+        raw.push(0);
+        raw.extend(colours.by_ref().take(width).flatten());
+    }
+
+    let mut header = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);     // 8 bits per channel, RGB, deflate, no filtering, no interlace
+
+    let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut image, b"IHDR", &header);
+    chunk(&mut image, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut image, b"IEND", &[]);
+    image
+}
+
+fn chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    image.extend(kind);
+    image.extend(data);
+    image.extend(crc32(&[kind.as_slice(), data].concat()).to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = data.chunks(0xffff).collect();
+
+    for (i, block) in blocks.iter().enumerate() {
+        let len = block.len() as u16;
+
+        stream.push((i + 1 == blocks.len()) as u8);     // the last block is marked final
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(*block);
+    }
+
+    // deflate wants at least one block, even for no data
+    if blocks.is_empty() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+// every frame as a numbered file in the directory, frame0000.png and so on
+pub fn write_stills(frames : &[Grid<u8>],
+                    palette: &Palette,
+                    scale  : usize,
+                    format : Still,
+                    dir    : &Path) -> io::Result<Vec<PathBuf>>
+{
+    fs::create_dir_all(dir)?;
+
+    frames.iter()
+          .enumerate()
+          .map(|(i, frame)| {
+              let path = dir.join(format!("frame{i:04}.{}", format.extension()));
+              fs::write(&path, format.encode(frame, palette, scale))?;
+              Ok(path)
+          })
+          .collect()
+}
+
+
+/* Animated GIFs */
+
+// all the frames in one GIF that loops forever. the frames must all be the same size
+pub fn gif(frames: &[Grid<u8>], palette: &Palette, scale: usize, delay: Duration) -> Vec<u8> {
+    let (width, height) = frames.first()
+                                .map_or((0, 0), |frame| (frame.width() * scale, frame.height() * scale));
+
+    assert!(frames.iter().all(|frame| (frame.width() * scale, frame.height() * scale) == (width, height)),
+            "every frame of a GIF has to be the same size");
+
+    // the colour table has a power of two entries, at least 2, and LZW codes start a bit wider
+    let mut table = palette.table();
+    let bits = table.len().next_power_of_two().trailing_zeros().max(1);
+    table.resize(1 << bits, [0, 0, 0]);
+
+    let mut image = b"GIF89a".to_vec();
+    image.extend((width as u16).to_le_bytes());
+    image.extend((height as u16).to_le_bytes());
+    image.extend([0x80 | 0x70 | (bits as u8 - 1), 0, 0]);     // global table, 8-bit colour, its size
+    image.extend(table.iter().flatten());
+
+    // loop forever
+    image.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+    for frame in frames {
+        image.extend([0x21, 0xf9, 0x04, 0x00]);
+        image.extend(delay.to_le_bytes());
+        image.extend([0x00, 0x00]);
+
+        image.push(0x2c);
+        image.extend([0, 0, 0, 0]);
+        image.extend((width as u16).to_le_bytes());
+        image.extend((height as u16).to_le_bytes());
+        image.push(0);
+
+        let min_code_size = bits.max(2);
+        let indices: Vec<u8> = pixels(frame, scale, |tile| palette.index(tile)).collect();
+
+        image.push(min_code_size as u8);
+
+        for block in lzw(&indices, min_code_size).chunks(255) {
+            image.push(block.len() as u8);
+            image.extend(block);
+        }
+        image.push(0);
+    }
+
+    image.push(0x3b);
+    image
+}
+
+// GIF's flavour of LZW: codes as narrow as they can be, growing a bit as the table fills, packed
+// least significant bit first. a full table is cleared and started again
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let mut encoder = Lzw::new(min_code_size);
+
+    encoder.out.write(encoder.clear, encoder.width);
+
+    let Some((&first, rest)) = indices.split_first() else {
+        encoder.out.write(encoder.clear + 1, encoder.width);
+        return encoder.out.finish()
+    };
+
+    let mut prefix = first as u16;
+
+    for &index in rest {
+        if let Some(&code) = encoder.table.get(&(prefix, index)) {
+            prefix = code;
+            continue
+        }
+
+        encoder.out.write(prefix, encoder.width);
+
+        if let Some(code) = encoder.next_code() {
+            encoder.table.insert((prefix, index), code);
+        }
+
+        prefix = index as u16;
+    }
+
+    // the decoder makes an entry after the last code as after any other, which can widen the end
+    encoder.out.write(prefix, encoder.width);
+    encoder.next_code();
+
+    encoder.out.write(encoder.clear + 1, encoder.width);
+    encoder.out.finish()
+}
+
+struct Lzw {
+    out          : Bits,
+    table        : HashMap<(u16, u8), u16>,
+    min_code_size: u32,
+    clear        : u16,
+    width        : u32,
+    last         : u16      // the last code given out, starting at the end code
+}
+
+impl Lzw {
+    const MAX_CODE: u16 = 4095;
+
+    fn new(min_code_size: u32) -> Self {
+        let clear = 1 << min_code_size;
+
+        Lzw { out: Bits::default(), table: HashMap::new(), min_code_size, clear, width: min_code_size + 1, last: clear + 1 }
+    }
+
+    // the code for the next table entry, widening codes once they need another bit. None if the
+    // table is full, in which case it's cleared and the decoder told so
+    fn next_code(&mut self) -> Option<u16> {
+        self.last += 1;
+
+        if self.last == 1 << self.width {
+            self.width += 1;
+        }
+
+        if self.last == Lzw::MAX_CODE {
+            self.out.write(self.clear, self.width);
+            self.table.clear();
+            self.width = self.min_code_size + 1;
+            self.last  = self.clear + 1;
+            return None
+        }
+
+        Some(self.last)
+    }
+}
+
+#[derive(Default)]
+struct Bits {
+    bytes  : Vec<u8>,
+    pending: u32,
+    count  : u32
+}
+
+impl Bits {
+    fn write(&mut self, code: u16, width: u32) {
+        self.pending |= (code as u32) << self.count;
+        self.count += width;
+
+        while self.count >= 8 {
+            self.bytes.push(self.pending as u8);
+            self.pending >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.pending as u8);
+        }
+        self.bytes
+    }
+}
+
+
+/* Terminal */
+
+// the frame in 24-bit colour, each tile two spaces wide so it comes out roughly square
+pub fn ansi(frame: &Grid<u8>, palette: &Palette) -> String {
+    let mut text = String::new();
+
+    for row in frame.rows() {
+        for &tile in row {
+            let [r, g, b] = palette.colour(tile);
+            text += &format!("\x1b[48;2;{r};{g};{b}m  ");
+        }
+        text += "\x1b[0m\n";
+    }
+
+    text
+}
+
+// draw each frame over the last one, with its number underneath
+pub fn play(frames: &[Grid<u8>], palette: &Palette, delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "\x1b[2J")?;
+
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(out, "\x1b[H{}frame {} of {}", ansi(frame, palette), i + 1, frames.len())?;
+        out.flush()?;
+
+        if i + 1 < frames.len() {
+            thread::sleep(delay);
+        }
+    }
+
+    Ok(())
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // compare with a file under tests/golden, or rewrite it when AOC_UPDATE_GOLDEN is set
+    fn golden(name: &str, actual: &[u8]) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+
+        if std::env::var_os("AOC_UPDATE_GOLDEN").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+        }
+
+        let expected = fs::read(&path).unwrap_or_else(|_| panic!("no golden file {}", path.display()));
+        assert!(expected == actual, "{name} doesn't match {}", path.display());
+    }
+
+    fn palette() -> Palette {
+        Palette::new([255, 0, 255]).with('.', [0, 0, 0])
+                                   .with('#', [255, 255, 255])
+                                   .with('x', [255, 0, 0])
+    }
+
+    // a blinker about to turn, the cells that go marked x
+    fn frames() -> Vec<Grid<u8>> {
+        [".....\n.....\nx#x..\n.....\n.....\n",
+         ".....\n.#...\n.#...\n.#...\n.....\n",
+         "?....\n.....\n.....\n.....\n....?\n"]
+            .into_iter()
+            .map(|text| Grid::parse_bytes(text, |_| true, "").unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = palette().with('#', [1, 2, 3]);
+
+        assert_eq!([1, 2, 3], palette.colour(b'#'));
+        assert_eq!([255, 0, 255], palette.colour(b'?'));
+        assert_eq!((0, 3), (palette.index(b'.'), palette.index(b'?')));
+        assert_eq!(4, palette.table().len());
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x091e_01de, adler32(b"123456789"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&frames()[0], &palette(), 2);
+
+        assert!(image.starts_with(b"P6\n10 10\n255\n"));
+        golden("blinker.ppm", &image);
+    }
+
+    #[test]
+    fn test_png() {
+        golden("blinker.png", &png(&frames()[0], &palette(), 2));
+    }
+
+    #[test]
+    fn test_gif() {
+        golden("blinker.gif", &gif(&frames(), &palette(), 3, Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_lzw_clears_a_full_table() {
+        // enough varied pixels to fill all 4096 codes more than once
+        let indices: Vec<u8> = (0..40_000u32).map(|i| (i * 7 % 13 + i / 1000 % 3) as u8 % 16).collect();
+
+        golden("lzw-long.bin", &lzw(&indices, 4));
+    }
+
+    #[test]
+    fn test_ansi() {
+        golden("blinker.ansi", ansi(&frames()[1], &palette()).as_bytes());
+
+        let mut out = vec![];
+        play(&frames()[..1], &palette(), Duration::ZERO, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with("\x1b[0m\nframe 1 of 1\n"));
+    }
+}
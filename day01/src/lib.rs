@@ -0,0 +1,141 @@
+// https://adventofcode.com/2025/day/1
+
+use common::{Answer, Solution};
+use common::parse::{ParseError, Source};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_rotations(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
+}
+
+pub struct Input {
+    rotations: Vec<i32>
+}
+
+pub fn part1(input: &Input) -> i32 {
+    let mut dial = 50;
+    let mut count_zeros = 0;
+
+    for rotation in &input.rotations {
+        dial = (dial + rotation) % 100;
+        if dial == 0 {
+            count_zeros += 1;
+        }
+    }
+
+    count_zeros
+}
+
+pub fn part2(input: &Input) -> i32 {
+    let mut dial = 50_i32;
+    let mut count_zeros = 0;
+
+    for rotation in &input.rotations {
+
+        let dial_before = dial;
+        let hundreds_before = dial_before.div_euclid(100);
+
+        dial += rotation;
+
+        let hundreds_now  = dial.div_euclid(100);
+        let hundreds_diff = (hundreds_now - hundreds_before).abs();
+
+        if rotation > &0 {
+            count_zeros += hundreds_diff;
+        }
+
+        if rotation < &0 {
+            count_zeros += hundreds_diff;
+
+            if dial_before % 100 == 0 {
+                count_zeros -= 1;
+            }
+
+            if dial % 100 == 0 {
+                count_zeros += 1;
+            }
+        }
+    }
+
+    count_zeros
+}
+
+
+/* Parsing */
+
+// ChatGPT 5.1
+pub fn parse_rotations(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
+
+    let rotations = text
+        .lines()
+        .map(|line| {
+            let Some(dir) = line.get(..1) else {
+                return Err(source.error(line, "a rotation like R10 or L3"))
+            };
+
+            let sign = match dir {                    // ("R", "10") or ("L", "3")
+                "R" => 1,
+                "L" => -1,
+                _   => return Err(source.error(dir, "L or R"))
+            };
+
+            let value: i32 = source.parse(&line[1..], "a number of clicks")?;
+
+            Ok(sign * value)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input {
+        rotations
+    })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day01>(1, "sample")
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        check::<Day01>(2, "sample")
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day01>(1, "input")
+    }
+
+    #[test]
+    fn test_part2() {
+        check::<Day01>(2, "input")
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_rotations("L68\nR48\nU5\n").err().unwrap();
+
+        assert_eq!((3, 1), (error.line, error.column));
+        assert_eq!("U", error.found);
+        assert_eq!("L or R", error.expected);
+
+        let error = parse_rotations("L68\nR\n").err().unwrap();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+}
@@ -1,30 +1,31 @@
 // https://adventofcode.com/2025/day/2
 
-fn main() {
-    let sample = parse_input("sample.txt");
-    let input  = parse_input("input.txt");
-
-    println!("Sample part 1: {}", part1(&sample));
-    println!("Sample part 2: {}", part2(&sample));
-    println!("Part 1:        {}", part1(&input));
-    println!("Part 2:        {}", part2(&input));
+use common::{Answer, Solution, Unsolved};
+use common::parse::{ParseError, Source};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(_input: &Input) -> impl Answer            { Unsolved }
 }
 
-struct Input {
+pub struct Input {
     intervals: Vec<(u64,u64)>
 }
 
-fn part1(input: &Input) -> u64 {
+pub fn part1(input: &Input) -> u64 {
     input.intervals
          .iter()
          .map(|(from, to)| sum_invalid_numbers_between(*from, *to))
          .sum()
 }
 
-fn part2(_input: &Input) -> u64 {
-    0
-}
-
 // store just one half of the InvalidNumber, there's no point in storing the same number twice
 #[derive(Debug, PartialEq)]
 struct InvalidNumber(u64);
@@ -94,6 +95,7 @@ fn next_higher_invalid_number(n: u64) -> InvalidNumber {
 }
 
 // count the number of invalid numbers between from and to, including the ends
+#[allow(dead_code)]
 fn count_invalid_numbers_between(from: u64, to: u64) -> u64 {
     
     let next_higher = next_higher_invalid_number(from);
@@ -171,36 +173,24 @@ fn split_number_in_half(n: u64) -> (u64, u64) {
 
 /* Parsing */
 
-use std::fs;
-
 // mostly ChatGPT 5.1
-fn parse_input(path: &str) -> Input {
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
 
     // Parse "a-b,c-d,..." into Vec<(i64, i64)>
-    let intervals = fs::read_to_string(path)
-        .unwrap()
+    let intervals = text
         .trim() // remove trailing newlines/whitespace
         .split(',') // split on commas
         .filter(|s| !s.is_empty())
         .map(|s| {
-            let mut parts = s.split('-');
-            let start = parts
-                .next()
-                .expect("missing start")
-                .trim()
-                .parse::<u64>()
-                .expect("invalid start number");
-            let end = parts
-                .next()
-                .expect("missing end")
-                .trim()
-                .parse::<u64>()
-                .expect("invalid end number");
-            (start, end)
+            let (start, end) = source.split_once(s, "-", "a range like 11-22")?;
+            let start = source.parse::<u64>(start.trim(), "a start number")?;
+            let end   = source.parse::<u64>(end.trim(), "an end number")?;
+            Ok((start, end))
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
-    Input { intervals }
+    Ok(Input { intervals })
 }
 
 
@@ -209,29 +199,21 @@ fn parse_input(path: &str) -> Input {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::answers::check;
 
     #[test]
     fn test_sample_part1() {
-        let input = parse_input("sample.txt");
-        assert_eq!(1227775554, part1(&input))
+        check::<Day02>(1, "sample")
     }
 
     #[test]
     fn test_sample_part2() {
-        let input = parse_input("sample.txt");
-        assert_eq!(4174379265, part2(&input))
+        check::<Day02>(2, "sample")
     }
 
     #[test]
     fn test_part1() {
-        let input = parse_input("input.txt");
-        assert_eq!(19128774598, part1(&input))
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = parse_input("input.txt");
-        assert_eq!(0, part2(&input))
+        check::<Day02>(1, "input")
     }
 
     #[test]
@@ -1,27 +1,32 @@
 // https://adventofcode.com/2025/day/3
 
-fn main() {
-    let sample = parse_input("sample.txt");
-    let input  = parse_input("input.txt");
-
-    println!("Sample part 1: {}", part1(&sample));
-    println!("Sample part 2: {}", part2(&sample));
-    println!("Part 1:        {}", part1(&input));
-    println!("Part 2:        {}", part2(&input));
+use common::{Answer, Solution};
+use common::parse::{ParseError, Source};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
 }
 
-struct Input {
+pub struct Input {
     banks: Vec<Vec<u8>>
 }
 
-fn part1(input: &Input) -> u64 {
+pub fn part1(input: &Input) -> u64 {
     input.banks
          .iter()
          .map(|bank| find_highest_joltage(bank) as u64)
          .sum()
 }
 
-fn part2(input: &Input) -> u64 {
+pub fn part2(input: &Input) -> u64 {
     input.banks
          .iter()
          .map(|bank| find_highest_joltage_k(bank, 12))
@@ -29,7 +34,7 @@ fn part2(input: &Input) -> u64 {
 }
 
 // the naive approach, hard-coded for only 2 battery selections, useful for part 1 only
-fn find_highest_joltage(bank: &Vec<u8>) -> u8 {
+fn find_highest_joltage(bank: &[u8]) -> u8 {
     let mut highest = 0;
 
     for (i, battery) in bank.iter().enumerate() {
@@ -50,10 +55,10 @@ fn find_highest_joltage(bank: &Vec<u8>) -> u8 {
 }
 
 // for part 2 we need a more general approach to compute for k battery selections
-fn find_highest_joltage_k(bank: &Vec<u8>, k: usize) -> u64 {
+fn find_highest_joltage_k(bank: &[u8], k: usize) -> u64 {
 
-    let mut joltage   = 0 as u64;
-    let mut left_edge = 0 as usize;
+    let mut joltage   = 0_u64;
+    let mut left_edge = 0_usize;
     let     n         = bank.len();
 
     for i in 0..k {
@@ -97,21 +102,22 @@ fn find_max_l(slice: &[u8]) -> usize {
 
 /* Parsing */
 
-use std::fs;
-
-fn parse_input(path: &str) -> Input {
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
 
-    let banks = fs::read_to_string(path)
-        .unwrap()
+    let banks = text
         .lines()
         .map(|line| {
-            line.bytes()
-                .map(|b| b - b'0')
-                .collect::<Vec<u8>>()
+            line.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(digit) => Ok(digit as u8),
+                    None        => Err(source.error(&line[i..i + c.len_utf8()], "a battery joltage from 1 to 9"))
+                })
+                .collect::<Result<Vec<u8>, _>>()
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Input { banks }
+    Ok(Input { banks })
 }
 
 
@@ -120,34 +126,31 @@ fn parse_input(path: &str) -> Input {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::answers::check;
 
     #[test]
     fn test_sample_part1() {
-        let input = parse_input("sample.txt");
-        assert_eq!(357, part1(&input))
+        check::<Day03>(1, "sample")
     }
 
     #[test]
     fn test_sample_part2() {
-        let input = parse_input("sample.txt");
-        assert_eq!(3121910778619, part2(&input))
+        check::<Day03>(2, "sample")
     }
 
     #[test]
     fn test_part1() {
-        let input = parse_input("input.txt");
-        assert_eq!(17031, part1(&input))
+        check::<Day03>(1, "input")
     }
 
     #[test]
     fn test_part2() {
-        let input = parse_input("input.txt");
-        assert_eq!(168575096286051, part2(&input))
+        check::<Day03>(2, "input")
     }
 
     #[test]
     fn test_find_highest_joltage() {
-        let input = parse_input("sample.txt");
+        let input = Day03::load("sample.txt").unwrap();
 
         /*
             987654321111111
@@ -164,7 +167,7 @@ mod tests {
 
     #[test]
     fn test_find_highest_joltage_k() {
-        let input = parse_input("sample.txt");
+        let input = Day03::load("sample.txt").unwrap();
 
         assert_eq!(find_highest_joltage_k(&input.banks[0], 12), 987654321111);
         assert_eq!(find_highest_joltage_k(&input.banks[1], 12), 811111111119);
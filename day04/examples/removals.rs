@@ -0,0 +1,41 @@
+// print which round each roll of a day 4 grid goes in, and optionally save it as a heatmap:
+//
+//   cargo run -p day04 --example removals -- day04/input.txt [heatmap.ppm [scale]]
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use common::Solution;
+use day04::Day04;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let Some(path) = args.first() else {
+        eprintln!("usage: removals INPUT [HEATMAP.ppm [SCALE]]");
+        return ExitCode::FAILURE
+    };
+
+    let input = match Day04::load(path) {
+        Ok(input)  => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE
+        }
+    };
+
+    let removals = day04::removals(&input);
+    println!("{removals}");
+
+    if let Some(heatmap) = args.get(1) {
+        let scale = args.get(2).and_then(|scale| scale.parse().ok()).unwrap_or(4);
+
+        if let Err(error) = fs::write(heatmap, removals.heatmap(scale)) {
+            eprintln!("couldn't write {heatmap}: {error}");
+            return ExitCode::FAILURE
+        }
+    }
+
+    ExitCode::SUCCESS
+}
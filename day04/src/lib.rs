@@ -0,0 +1,445 @@
+// https://adventofcode.com/2025/day/4
+
+use std::fmt;
+
+use common::{Answer, Solution};
+use common::automaton::{Automaton, Rule};
+use common::cores::{grid_cores, Core};
+use common::grid::{Grid, EIGHT};
+use common::parse::ParseError;
+use common::render::Palette;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
+
+    // both parts are questions about the same decomposition
+    fn parts(input: &Input) -> (impl Answer, impl Answer) { parts(input) }
+
+    fn frames(input: &Input) -> Vec<Grid<u8>>             { frames(input) }
+    fn palette() -> Palette                               { palette() }
+}
+
+pub struct Input {
+    grid: Grid<u8>
+}
+
+// a roll can be reached if fewer than 4 of the 8 cells around it hold rolls. removing the ones
+// that can be, again and again, peels the rolls down to their 4-core: the rolls that are never
+// removed are the ones with a core number of 4 or more
+const ACCESSIBLE: usize = 4;
+
+fn cores(input: &Input) -> Grid<Option<Core>> {
+    grid_cores(&input.grid, |&tile| tile == b'@', &EIGHT)
+}
+
+pub fn part1(input: &Input) -> usize {
+    cores(input).count(|core| core.is_some_and(|core| core.degree < ACCESSIBLE))
+}
+
+pub fn part2(input: &Input) -> usize {
+    cores(input).count(|core| core.is_some_and(|core| !core.in_k_core(ACCESSIBLE)))
+}
+
+fn parts(input: &Input) -> (usize, usize) {
+    let cores = cores(input);
+
+    (cores.count(|core| core.is_some_and(|core| core.degree < ACCESSIBLE)),
+     cores.count(|core| core.is_some_and(|core| !core.in_k_core(ACCESSIBLE))))
+}
+
+// find the coordinates of the rolls that have fewer than 4 rolls around them
+fn accessible_rolls(grid: &Grid<u8>) -> Vec<(usize,usize)> {
+    
+    let mut coords = vec![];
+
+    // how many rolls there are in each column of the three rows centred on the current one
+    let mut column_rolls = vec![0u8; grid.width()];
+
+    for r in 0..grid.height() {
+
+        column_rolls.fill(0);
+
+        // the rows above and below, if there are any, straight out of the grid's buffer
+        for row in r.saturating_sub(1) .. (r + 2).min(grid.height()) {
+            for (count, &tile) in column_rolls.iter_mut().zip(grid.row(row)) {
+                *count += (tile == b'@') as u8;
+            }
+        }
+
+        for (c, &tile) in grid.row(r).iter().enumerate() {
+
+            if tile != b'@' {
+                continue
+            }
+
+            // the 3x3 block around this roll, less the roll itself
+            let span  = c.saturating_sub(1) .. (c + 2).min(grid.width());
+            let count = column_rolls[span].iter().sum::<u8>() - 1;
+
+            // if there are fewer than 4 rolls in our neighbourhood, remember this coordinate
+            if count < 4 {
+                coords.push((r,c));
+            }
+        }
+    }
+
+    coords
+}
+
+/* As an automaton */
+
+// the forklifts' rule: a roll with fewer than 4 rolls around it is removed and nothing new ever
+// appears, so each step of the automaton is a round of removals and the fixed point is what's left
+pub fn forklifts() -> Automaton {
+    Automaton::new(Rule::new(&EIGHT).removes(0..ACCESSIBLE))
+}
+
+pub fn rolls(input: &Input) -> Grid<bool> {
+    input.grid.map(|&tile| tile == b'@')
+}
+
+
+/* Removal rounds */
+
+// what happened to each cell when the rolls were removed a round at a time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fate {
+    Empty,
+    Removed(usize),     // in this round, counting from 1
+    Never
+}
+
+pub struct Removals {
+    pub fates    : Grid<Fate>,
+    pub per_round: Vec<usize>,     // how many rolls went in each round, round 1 first
+    pub stable   : Grid<u8>        // the rolls that are left when no more can be removed
+}
+
+// part 2 a round at a time. only rolls next to ones that were just removed can have become
+// accessible, so after the first round those are the only ones looked at
+pub fn removals(input: &Input) -> Removals {
+    let mut grid  = input.grid.clone();
+    let mut fates = grid.map(|&tile| if tile == b'@' { Fate::Never } else { Fate::Empty });
+    let mut per_round = vec![];
+
+    let mut rolls = accessible_rolls(&grid);
+
+    while !rolls.is_empty() {
+        per_round.push(rolls.len());
+
+        for &coord in &rolls {
+            fates[coord] = Fate::Removed(per_round.len());
+        }
+
+        remove_rolls(&rolls, &mut grid);
+
+        let mut next: Vec<(usize, usize)> = rolls.iter()
+            .flat_map(|&(r, c)| grid.neighbours8(r, c))
+            .filter(|&coord| grid[coord] == b'@' && is_accessible(&grid, coord))
+            .collect();
+
+        next.sort();
+        next.dedup();
+
+        rolls = next;
+    }
+
+    Removals { fates, per_round, stable: grid }
+}
+
+fn is_accessible(grid: &Grid<u8>, (r, c): (usize, usize)) -> bool {
+    grid.neighbours8(r, c)
+        .filter(|&coord| grid[coord] == b'@')
+        .count() < 4
+}
+
+fn remove_rolls(coords: &[(usize, usize)],
+                grid  : &mut Grid<u8>)
+{
+    for &coord in coords {
+        grid[coord] = b'.'
+    }
+}
+
+// the grid before each round with the rolls about to go marked 'x', then what's left at the end
+pub fn frames(input: &Input) -> Vec<Grid<u8>> {
+    let removals = removals(input);
+
+    (0..=removals.per_round.len())
+        .map(|round| removals.fates.map(|fate| match *fate {
+            Fate::Empty                        => b'.',
+            Fate::Removed(r) if r <= round     => b'.',
+            Fate::Removed(r) if r == round + 1 => b'x',
+            _                                  => b'@'
+        }))
+        .collect()
+}
+
+pub fn palette() -> Palette {
+    Palette::new([255, 0, 255]).with('.', [32, 32, 32])
+                               .with('@', [230, 220, 200])
+                               .with('x', [220, 40, 40])
+}
+
+impl Removals {
+    // one pixel per cell, or a square of them with a bigger scale. empty cells are black and rolls
+    // that never went are white, the rest run from blue in the first round to red in the last.
+    // a binary PPM, which most image viewers open
+    pub fn heatmap(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.fates.width() * scale, self.fates.height() * scale);
+        let last = self.per_round.len().saturating_sub(1).max(1);
+
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+        for row in 0..height {
+            for col in 0..width {
+                let colour = match self.fates[(row / scale, col / scale)] {
+                    Fate::Empty          => [0, 0, 0],
+                    Fate::Never          => [255, 255, 255],
+                    Fate::Removed(round) => {
+                        let heat = (255 * (round - 1) / last) as u8;
+                        [heat, 64, 255 - heat]
+                    }
+                };
+
+                image.extend_from_slice(&colour);
+            }
+        }
+
+        image
+    }
+}
+
+// the grid with each roll marked by the round it went in, 1-9 then a-z, '+' for any later round,
+// '@' for those that never went, then the count for each round
+impl fmt::Display for Removals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.fates.rows() {
+            let line: String = row.iter()
+                .map(|fate| match *fate {
+                    Fate::Empty          => '.',
+                    Fate::Never          => '@',
+                    Fate::Removed(round) => char::from_digit(round as u32, 36).unwrap_or('+')
+                })
+                .collect();
+
+            writeln!(f, "{line}")?;
+        }
+
+        writeln!(f)?;
+
+        for (round, count) in self.per_round.iter().enumerate() {
+            writeln!(f, "round {:>3}: {count}", round + 1)?;
+        }
+
+        write!(f, "never    : {}", self.stable.count(|&tile| tile == b'@'))
+    }
+}
+
+
+/* Parsing */
+
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse_bytes(text,
+                                 |b| matches!(b, b'@' | b'.'),
+                                 "a roll '@' or an empty space '.'")?;
+
+    Ok(Input { grid })
+}
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+    use common::automaton::{Outcome, Update};
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day04>(1, "sample")
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        check::<Day04>(2, "sample")
+    }
+
+    #[test]
+    fn test_stress_part1() {
+        check::<Day04>(1, "stress")
+    }
+
+    #[test]
+    fn test_stress_part2() {
+        check::<Day04>(2, "stress")
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day04>(1, "input")
+    }
+
+    #[test]
+    fn test_part2() {
+        check::<Day04>(2, "input")
+    }
+
+    // the whole grid searched again every round, as part 2 was first written
+    fn part2_by_rounds(input: &Input) -> usize {
+        let mut grid = input.grid.clone();
+        let mut count_rolls = 0;
+
+        loop {
+            let rolls = accessible_rolls(&grid);
+            if rolls.is_empty() {
+                break
+            }
+            count_rolls += rolls.len();
+            remove_rolls(&rolls, &mut grid);
+        }
+
+        count_rolls
+    }
+
+    // two-row strips of rolls joined at alternate ends into one long snake. only the rolls at its
+    // ends and its inside corners are accessible, so it comes apart a few rolls a round and the
+    // number of rounds grows with the area of the grid. stress.txt is serpentine(299)
+    fn serpentine(size: usize) -> String {
+        let mut text = String::new();
+
+        for row in 0..size {
+            let line: String = (0..size)
+                .map(|col| {
+                    let strip = row / 3;
+                    let joint = if strip % 2 == 0 { col >= size - 2 } else { col < 2 };
+
+                    match row % 3 {
+                        0 | 1                    => '@',
+                        _ if row + 1 < size && joint => '@',
+                        _                        => '.'
+                    }
+                })
+                .collect();
+
+            text += &line;
+            text += "\n";
+        }
+
+        text
+    }
+
+    #[test]
+    fn test_stress_input() {
+        let path = common::day_dir(Day04::DAY).join("stress.txt");
+
+        assert_eq!(serpentine(299), std::fs::read_to_string(path).unwrap());
+    }
+
+    #[test]
+    fn test_removals() {
+        let text  = "@@@@...\n@@@@.@@\n@@@@@@.\n@@@@...\n";
+        let input = parse_input(text).unwrap();
+
+        let removals = removals(&input);
+
+        assert_eq!(vec![6, 1, 1], removals.per_round);
+        assert_eq!(Fate::Removed(2), removals.fates[(2, 4)]);
+        assert_eq!(Fate::Never, removals.fates[(1, 1)]);
+        assert_eq!(Fate::Empty, removals.fates[(0, 4)]);
+        assert_eq!(Grid::parse_bytes(".@@....\n@@@@...\n@@@@...\n.@@....\n", |_| true, "").unwrap(),
+                   removals.stable);
+
+        assert_eq!("\
+1@@1...
+@@@@.11
+@@@@21.
+1@@3...
+
+round   1: 6
+round   2: 1
+round   3: 1
+never    : 12", removals.to_string());
+
+        let heatmap = removals.heatmap(2);
+        assert!(heatmap.starts_with(b"P6\n14 8\n255\n"));
+        assert_eq!(b"P6\n14 8\n255\n".len() + 14 * 8 * 3, heatmap.len());
+    }
+
+    #[test]
+    fn test_removals_match_the_parts() {
+        let sample = std::fs::read_to_string(common::day_dir(Day04::DAY).join("sample.txt")).unwrap();
+
+        for text in [sample, serpentine(59)] {
+            let input    = parse_input(&text).unwrap();
+            let removals = removals(&input);
+
+            assert_eq!(part1(&input), removals.per_round[0]);
+            assert_eq!(part2(&input), removals.per_round.iter().sum::<usize>());
+            assert_eq!(part2_by_rounds(&input), removals.per_round.iter().sum::<usize>());
+
+            let never = removals.fates.count(|&fate| fate == Fate::Never);
+            assert_eq!(never, removals.stable.count(|&tile| tile == b'@'));
+        }
+    }
+
+    #[test]
+    fn test_forklifts() {
+        let sample = std::fs::read_to_string(common::day_dir(Day04::DAY).join("sample.txt")).unwrap();
+
+        for text in [sample, serpentine(29)] {
+            let input = parse_input(&text).unwrap();
+            let rolls = rolls(&input);
+            let count = |grid: &Grid<bool>| grid.count(|&roll| roll);
+
+            let mut first = rolls.clone();
+            forklifts().step(&mut first);
+            assert_eq!(part1(&input), count(&rolls) - count(&first));
+
+            // removing rolls as soon as they're accessible takes fewer rounds but removes the same ones
+            for update in [Update::Sync, Update::Async] {
+                let run = forklifts().update(update).run(&rolls, usize::MAX);
+
+                assert_eq!(Outcome::FixedPoint, run.outcome);
+                assert_eq!(part2(&input), count(&rolls) - count(&run.grid));
+            }
+
+            let run = forklifts().run(&rolls, usize::MAX);
+            assert_eq!(removals(&input).per_round.len(), run.steps);
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let input  = parse_input("@@@@...\n@@@@.@@\n@@@@@@.\n@@@@...\n").unwrap();
+        let frames = frames(&input);
+
+        let text = |frame: &Grid<u8>| frame.rows().map(|row| String::from_utf8(row.to_vec()).unwrap() + "\n").collect::<String>();
+
+        assert_eq!(4, frames.len());
+        assert_eq!("x@@x...\n@@@@.xx\n@@@@@x.\nx@@@...\n", text(&frames[0]));
+        assert_eq!(".@@....\n@@@@...\n@@@@x..\n.@@@...\n", text(&frames[1]));
+        assert_eq!(removals(&input).stable, frames[3]);
+    }
+
+    #[test]
+    fn test_cores_match_rounds() {
+        let sample = std::fs::read_to_string(common::day_dir(Day04::DAY).join("sample.txt")).unwrap();
+
+        for text in [sample, serpentine(14), serpentine(59), serpentine(60)] {
+            let input = parse_input(&text).unwrap();
+            assert_eq!(part2_by_rounds(&input), part2(&input));
+            assert_eq!(accessible_rolls(&input.grid).len(), part1(&input));
+            assert_eq!((part1(&input), part2(&input)), parts(&input));
+        }
+    }
+
+}
@@ -1,21 +1,26 @@
 // https://adventofcode.com/2025/day/5
 
-fn main() {
-    let sample = parse_input("sample.txt");
-    let input  = parse_input("input.txt");
-
-    println!("Sample part 1: {}", part1(&sample));
-    println!("Sample part 2: {}", part2(&sample));
-    println!("Part 1:        {}", part1(&input));
-    println!("Part 2:        {}", part2(&input));
+use common::{Answer, Solution};
+use common::parse::{ParseError, Source};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
 }
 
-struct Input {
+pub struct Input {
     ranges: Vec<(u64,u64)>,
     ingredient_ids: Vec<u64>
 }
 
-fn part1(input: &Input) -> usize {
+pub fn part1(input: &Input) -> usize {
     
     input.ingredient_ids
          .iter()
@@ -27,7 +32,7 @@ fn part1(input: &Input) -> usize {
          .count()
 }
 
-fn part2(input: &Input) -> u64 {
+pub fn part2(input: &Input) -> u64 {
 
     let mut intervals: Vec<Interval> = vec![];
 
@@ -160,9 +165,8 @@ where
 /* Parsing */
 
 // ChatGPT 5.1
-fn parse_input(filename: &str) -> Input {
-    // Assume file always exists and is valid
-    let text = std::fs::read_to_string(filename).unwrap();
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
 
     let mut ranges = Vec::new();
     let mut ids = Vec::new();
@@ -180,18 +184,22 @@ fn parse_input(filename: &str) -> Input {
 
         if parsing_ranges {
             // Parse "a-b"
-            let (a, b) = trimmed.split_once('-').unwrap();
-            ranges.push((a.parse().unwrap(), b.parse().unwrap()));
+            let (a, b) = source.split_once(trimmed, "-", "a range of ingredient IDs like 3-5")?;
+            ranges.push((source.parse(a, "an ingredient ID")?, source.parse(b, "an ingredient ID")?));
         } else {
             // Parse ingredient ID
-            ids.push(trimmed.parse().unwrap());
+            ids.push(source.parse(trimmed, "an ingredient ID")?);
         }
     }
 
-    Input {
+    if parsing_ranges {
+        return Err(source.end("a blank line and then the available ingredient IDs"))
+    }
+
+    Ok(Input {
         ranges,
         ingredient_ids: ids,
-    }
+    })
 }
 
 
@@ -200,29 +208,26 @@ fn parse_input(filename: &str) -> Input {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::answers::check;
 
     #[test]
     fn test_sample_part1() {
-        let input = parse_input("sample.txt");
-        assert_eq!(3, part1(&input))
+        check::<Day05>(1, "sample")
     }
 
     #[test]
     fn test_sample_part2() {
-        let input = parse_input("sample.txt");
-        assert_eq!(14, part2(&input))
+        check::<Day05>(2, "sample")
     }
 
     #[test]
     fn test_part1() {
-        let input = parse_input("input.txt");
-        assert_eq!(840, part1(&input))
+        check::<Day05>(1, "input")
     }
 
     #[test]
     fn test_part2() {
-        let input = parse_input("input.txt");
-        assert_eq!(359913027576322, part2(&input))
+        check::<Day05>(2, "input")
     }
 
 }
@@ -0,0 +1,233 @@
+// https://adventofcode.com/2025/day/6
+
+use common::{Answer, Solution};
+use common::grid::Grid;
+use common::parse::{ParseError, Source};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
+
+    // both parts fall out of the same pass over the blocks
+    fn parts(input: &Input) -> (impl Answer, impl Answer) { calculate(input) }
+}
+
+pub struct Input {
+    grid: Grid<u8>
+}
+
+pub fn part1(input: &Input) -> u64 { calculate(input).0 }
+pub fn part2(input: &Input) -> u64 { calculate(input).1 }
+
+fn calculate(input: &Input) -> (u64, u64)
+{
+    let blocks: Vec<Block> = parse_grid_into_blocks(&input.grid);
+
+    let mut horiz = 0;
+    let mut vert  = 0;
+
+    for block in blocks {
+        if block.symbol == b'*' {
+            horiz += block.horizontal_numbers.iter().product::<u64>();
+            vert  += block.vertical_numbers  .iter().product::<u64>();
+        } else {
+            horiz += block.horizontal_numbers.iter().sum::<u64>();
+            vert  += block.vertical_numbers  .iter().sum::<u64>();
+        }
+    }
+
+    (horiz, vert)
+}
+
+// one symbol with both its horizontal numbers and vertical numbers
+struct Block {
+    symbol: u8,
+    horizontal_numbers: Vec<u64>,
+    vertical_numbers  : Vec<u64>
+}
+
+/*
+    123 328  51 64 
+     45 64  387 23 
+      6 98  215 314
+    *   +   *   +  
+*/
+
+// strategy:
+//
+//  locate symbols
+//  for each symbol:
+//      get horizontal numbers
+//      get vertical numbers (number of vertical numbers determined by longest horizontal number)
+//
+fn parse_grid_into_blocks(grid: &Grid<u8>) -> Vec<Block>
+{
+    // find the locations of the symbols + and * on the last row
+    let symbols = grid.row(grid.height() - 1)
+                      .iter()
+                      .enumerate()
+                      .filter(|&(_, symbol)| *symbol == b'+' ||
+                                             *symbol == b'*');
+    let mut blocks = vec![];
+
+    for (col, symbol) in symbols
+    {
+        let mut horizontal_numbers = vec![];
+        let mut vertical_numbers   = vec![];
+
+        for row in 0 .. grid.height() - 1 {
+            horizontal_numbers.push(
+                get_horizontal_number(row, col, grid)
+            );
+        }
+
+        // here we can benefit a bit from parsing the horizontal/vertical numbers together: we know
+        // how many columns of numbers to get because it's the length of the largest horizontal number
+        let column_count = horizontal_numbers.iter()
+                                             .map(|&number| num_digits(number))
+                                             .max()
+                                             .unwrap();
+
+        for column in col .. col+column_count {
+            vertical_numbers.push(
+                get_vertical_number(column, grid)
+            );
+        }
+
+        blocks.push(Block {
+            symbol: *symbol,
+            horizontal_numbers,
+            vertical_numbers
+        });
+    }
+                                             
+    blocks
+}
+
+// parse the horizontal number starting at a row/col on the grid (potentially starting with spaces)
+fn get_horizontal_number(row: usize, column: usize, grid: &Grid<u8>) -> u64
+{
+    let digits =
+        grid.row(row)[column..]
+            .iter()
+            .skip_while(|b| b.is_ascii_whitespace())
+            .take_while(|b| b.is_ascii_digit());
+
+    to_number(digits)
+}
+
+// parse the vertical number from the digits in a given column
+fn get_vertical_number(column: usize, grid: &Grid<u8>) -> u64
+{
+    let digits =
+        (0 .. grid.height()-1)                      // the range of row offsets, except for the last row
+            .map(|row| &grid[(row, column)])        // get the byte at this row (the column is fixed)
+            .filter(|b| !b.is_ascii_whitespace());  // ignore blanks
+
+    to_number(digits)
+}
+
+// the digits' value, read straight off the bytes instead of collecting them into a String first
+fn to_number<'a>(digits: impl Iterator<Item = &'a u8>) -> u64 {
+    digits.fold(0, |number, digit| number * 10 + (digit - b'0') as u64)
+}
+
+// count number of digits in a number
+fn num_digits(n: u64) -> usize {
+    if n == 0 {
+        1
+    } else {
+        (n as f64).log10().floor() as usize + 1
+    }
+}
+
+
+/* Parsing */
+
+// collect the chars into a 2D grid, parsing into blocks happens later. the number rows hold
+// digits and spaces, the last row the operators, and every row is the same width
+pub fn parse_input<'a>(text: &'a str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
+    let lines: Vec<&str> = text.lines().collect();
+
+    let Some((symbols, numbers)) = lines.split_last() else {
+        return Err(source.end("rows of numbers and a row of operators"))
+    };
+
+    if numbers.is_empty() {
+        return Err(source.end("a row of operators after the numbers"))
+    }
+
+    // the whole char that's wrong, not just its first byte, and not the \r of a \r\n ending
+    let bad = |line: &'a str, ok: fn(char) -> bool| {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        line.char_indices().find(|&(_, c)| !ok(c)).map(|(i, c)| &line[i..i + c.len_utf8()])
+    };
+
+    for &line in numbers {
+        if let Some(found) = bad(line, |c| c.is_ascii_digit() || c == ' ') {
+            return Err(source.error(found, "a digit or a space"))
+        }
+    }
+
+    if let Some(found) = bad(symbols, |c| "+* ".contains(c)) {
+        return Err(source.error(found, "an operator + or *"))
+    }
+
+    // every char is fine by now, this just makes sure the rows are all the same width
+    let grid = Grid::parse_bytes(text, |_| true, "any char")?;
+
+    Ok(Input { grid })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day06>(1, "sample")
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        check::<Day06>(2, "sample")
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day06>(1, "input")
+    }
+
+    #[test]
+    fn test_part2() {
+        check::<Day06>(2, "input")
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("1\u{e9}\n+ \n").err().unwrap();
+
+        assert_eq!((1, 2), (error.line, error.column));
+        assert_eq!("\u{e9}", error.found);
+
+        let error = parse_input("12\n+-\n").err().unwrap();
+        assert_eq!((2, 2), (error.line, error.column));
+
+        // windows line endings are fine, and so is a last line without one
+        assert!(parse_input("12 \r\n 3 \r\n+ *\r\n").is_ok());
+        assert!(parse_input("12 \r\n 3 \r\n+ *\r").is_ok());
+    }
+
+}
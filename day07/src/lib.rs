@@ -0,0 +1,232 @@
+// https://adventofcode.com/2025/day/7
+
+use common::{Answer, Solution};
+use common::grid::Grid;
+use common::parse::{ParseError, Source};
+use common::render::Palette;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
+
+    fn frames(input: &Input) -> Vec<Grid<u8>>          { frames(input) }
+    fn palette() -> Palette                            { palette() }
+}
+
+pub struct Input {
+    manifold: Grid<u8>
+}
+
+pub fn part1(input: &Input) -> u64 {
+    let s_column = get_s_column(&input.manifold);
+
+    let mut beam_columns = vec![ s_column ];
+    let mut split_count = 0;
+
+    for row in 2 .. input.manifold.height() {
+        let mut next_beams = vec![];
+
+        for col in beam_columns {
+            match input.manifold[(row, col)] {
+                
+                b'.' => {
+                    next_beams.push(col)
+                },
+
+                b'^' => {
+                    next_beams.push(col - 1);
+                    next_beams.push(col + 1);
+                    split_count += 1;
+                },
+
+                _ => panic!("unknown tile type")
+            } 
+        }
+
+        next_beams.sort();
+        next_beams.dedup();
+
+        beam_columns = next_beams.into_iter().collect()
+    }
+
+    split_count
+}
+
+
+pub fn part2(input: &Input) -> u64 {
+    // fmap (const None) manifold
+    let mut memos: Grid<Option<u64>> = input.manifold.map(|_| None);
+
+    go( 1,
+        get_s_column(&input.manifold),
+        &input.manifold,
+        &mut memos)
+}
+
+fn go(row     : usize,
+      col     : usize,
+      manifold: &Grid<u8>,
+      memos   : &mut Grid<Option<u64>> ) -> u64
+{
+    if row == manifold.height() - 1 {
+        return 1
+    }
+
+    // check right away if we've been to this tile before
+    if let Some(memo) = memos[(row, col)] {
+        return memo
+    }
+
+    let path_count = match manifold[(row, col)]
+    {
+        // we're at a splitter, so recurse left, right and add the results
+        b'^' => go(row+1, col-1, manifold, memos)
+              + go(row+1, col+1, manifold, memos),
+
+        // go down a row
+        b'.' => go(row+1, col, manifold, memos),
+
+         _   => panic!("unknown tile type")
+    };
+    
+    memos[(row, col)] = Some(path_count);
+
+    path_count
+}
+
+// the beams working their way down the manifold a row at a time, marked '|' as in the puzzle
+pub fn frames(input: &Input) -> Vec<Grid<u8>> {
+    let mut frame  = input.manifold.clone();
+    let mut beams  = vec![ get_s_column(&input.manifold) ];
+    let mut frames = vec![ frame.clone() ];
+
+    for row in 1 .. frame.height() {
+        let mut next_beams = vec![];
+
+        for col in beams {
+            match frame[(row, col)] {
+                b'^' => next_beams.extend([col - 1, col + 1]),
+                _    => next_beams.push(col)
+            }
+        }
+
+        next_beams.sort();
+        next_beams.dedup();
+
+        for &col in &next_beams {
+            if frame[(row, col)] == b'.' {
+                frame[(row, col)] = b'|';
+            }
+        }
+
+        beams = next_beams;
+        frames.push(frame.clone());
+    }
+
+    frames
+}
+
+pub fn palette() -> Palette {
+    Palette::new([255, 0, 255]).with('.', [16, 16, 32])
+                               .with('S', [60, 200, 60])
+                               .with('^', [240, 200, 40])
+                               .with('|', [80, 200, 255])
+}
+
+fn get_s_column(manifold: &Grid<u8>) -> usize {
+    manifold.position_of(&b'S')
+            .unwrap()
+            .1
+}
+
+
+/* Parsing */
+
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let manifold = Grid::parse_bytes(text,
+                                     |b| matches!(b, b'.' | b'^' | b'S'),
+                                     "a tile '.', '^' or 'S'")?;
+
+    let source = Source::new(text);
+
+    if manifold.position_of(&b'S').is_none_or(|(row, _)| row != 0) {
+        return Err(source.error(text.lines().next().unwrap_or(text), "the start 'S' on the first row"))
+    }
+
+    if manifold.height() < 2 {
+        return Err(source.end("a row below the start for the beam to go down"))
+    }
+
+    // a splitter sends beams out both sides, so there has to be a column on each side of it
+    let edges = [0, manifold.width() - 1];
+
+    for (line, row) in text.lines().zip(manifold.rows()) {
+        if let Some(&col) = edges.iter().find(|&&col| row[col] == b'^') {
+            return Err(source.error(&line[col..col + 1], "a tile '.' or 'S' at the edge, a splitter '^' needs a column either side"))
+        }
+    }
+
+    Ok(Input { manifold })
+}
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day07>(1, "sample")
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        check::<Day07>(2, "sample")
+    }
+
+    #[test]
+    fn test_frames() {
+        let input  = parse_input("..S..\n.....\n..^..\n.....\n").unwrap();
+        let frames = frames(&input);
+
+        let text = |frame: &Grid<u8>| frame.rows().map(|row| String::from_utf8(row.to_vec()).unwrap() + "\n").collect::<String>();
+
+        assert_eq!(4, frames.len());
+        assert_eq!("..S..\n.....\n..^..\n.....\n", text(&frames[0]));
+        assert_eq!("..S..\n..|..\n.|^|.\n.|.|.\n", text(&frames[3]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("..S..\n").err().unwrap();
+        assert_eq!(2, error.line);
+        assert_eq!("a row below the start for the beam to go down", error.expected);
+
+        let error = parse_input("..S..\n.....\n^....\n").err().unwrap();
+        assert_eq!((3, 1), (error.line, error.column));
+        assert_eq!("^", error.found);
+
+        let error = parse_input("..S..\n....^\n").err().unwrap();
+        assert_eq!((2, 5), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day07>(1, "input")
+    }
+
+    #[test]
+    fn test_part2() {
+        check::<Day07>(2, "input")
+    }
+
+}
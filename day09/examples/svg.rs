@@ -0,0 +1,40 @@
+// draw a day 9 floor as an SVG, with the loop and the rectangle each part picked, to a file or
+// to stdout:
+//
+//   cargo run -p day09 --example svg -- day09/input.txt [floor.svg]
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use common::Solution;
+use day09::Day09;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let Some(path) = args.first() else {
+        eprintln!("usage: svg INPUT [OUTPUT.svg]");
+        return ExitCode::FAILURE
+    };
+
+    let input = match Day09::load(path) {
+        Ok(input)  => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE
+        }
+    };
+
+    let svg = day09::svg(&input);
+
+    match args.get(1) {
+        None         => print!("{svg}"),
+        Some(output) => if let Err(error) = fs::write(output, svg) {
+            eprintln!("couldn't write {output}: {error}");
+            return ExitCode::FAILURE
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,496 @@
+// https://adventofcode.com/2025/day/9
+
+use std::fmt::Write;
+
+use common::{Answer, Solution};
+use common::grid::{Grid, FOUR};
+use common::parse::{ParseError, Source};
+use common::polygon;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
+}
+
+pub struct Input {
+    red_tiles: Vec<(u32, u32)>
+}
+
+// the biggest rectangle has one corner out at the edge of the tiles and the other out at the
+// opposite edge, so only the tiles on the frontier towards each corner of the floor can be
+// corners of it. pair up the frontiers on opposite corners instead of every tile with every other
+pub fn part1(input: &Input) -> usize {
+    largest_rectangle(input).map_or(0, |(tile1, tile2)| area_between(tile1, tile2))
+}
+
+// the red tiles at opposite corners of the biggest rectangle
+pub fn largest_rectangle(input: &Input) -> Option<((u32, u32), (u32, u32))> {
+    let tiles = &input.red_tiles;
+
+    let diagonals = [
+        (frontier(tiles, false, false), frontier(tiles, true, true)),  // top-left, bottom-right
+        (frontier(tiles, false, true ), frontier(tiles, true, false))  // bottom-left, top-right
+    ];
+
+    let mut largest = None;
+    let mut largest_area = 0_usize;
+
+    for (corners1, corners2) in &diagonals {
+    for &tile1 in corners1 {
+    for &tile2 in corners2 {
+        let area = area_between(tile1, tile2);
+        if area > largest_area {
+            largest_area = area;
+            largest = Some((tile1, tile2));
+        }
+    }}}
+
+    largest
+}
+
+// the tiles with no other tile at least as far towards a corner of the floor on both axes. any
+// rectangle with a corner inside the frontier is no bigger than one with that corner moved out to
+// the frontier tile beyond it. with no flips the corner is the top left (smallest x and y)
+fn frontier(tiles: &[(u32, u32)], flip_x: bool, flip_y: bool) -> Vec<(u32, u32)> {
+    // complementing a coordinate reverses its order
+    let towards = |(x, y): (u32, u32)| {
+        (if flip_x { !x } else { x },
+         if flip_y { !y } else { y })
+    };
+
+    let mut sorted = tiles.to_vec();
+    sorted.sort_by_key(|&tile| towards(tile));
+
+    // sweeping from the corner's side, a tile is on the frontier if it's further towards the
+    // corner's other side than every tile before it
+    let mut frontier = vec![];
+    let mut furthest = u32::MAX;
+
+    for tile in sorted {
+        let (_, y) = towards(tile);
+        if y < furthest {
+            furthest = y;
+            frontier.push(tile);
+        }
+    }
+
+    frontier
+}
+
+// the same search, but only for rectangles with every tile inside the loop (or on it)
+pub fn part2(input: &Input) -> usize {
+    largest_rectangle_inside(input).map_or(0, |(tile1, tile2)| area_between(tile1, tile2))
+}
+
+pub fn largest_rectangle_inside(input: &Input) -> Option<((u32, u32), (u32, u32))> {
+    let floor = Floor::new(&input.red_tiles);
+    let mut largest = None;
+    let mut largest_area = 0_usize;
+
+    for (i, &tile1) in input.red_tiles.iter().enumerate() {
+    for &tile2 in &input.red_tiles[i+1..] {
+        let area = area_between(tile1, tile2);
+        if area > largest_area && floor.is_inside(tile1, tile2) {
+            largest_area = area;
+            largest = Some((tile1, tile2));
+        }
+    }}
+
+    largest
+}
+
+
+/* Compressed floor */
+
+// the coordinates run to the hundred-thousands, far too many tiles to look at one by one. but the
+// loop only turns at red tiles, so squash the floor down to the columns and rows that have one,
+// with a column (or row) between each pair standing for all the tiles between them, if there
+// are any. every squashed cell is then wholly inside the loop or wholly outside it
+struct Floor {
+    xs: Axis,
+    ys: Axis,
+
+    // how many squashed cells outside the loop there are above and to the left of each cell
+    outside: Grid<u32>
+}
+
+impl Floor {
+    // parse_input has already checked the tiles make a loop
+    fn new(red_tiles: &[(u32, u32)]) -> Self {
+        let xs = Axis::new(red_tiles.iter().map(|tile| tile.0));
+        let ys = Axis::new(red_tiles.iter().map(|tile| tile.1));
+
+        let mut cells = Grid::new(xs.len(), ys.len(), Cell::Unknown);
+
+        for (i, &tile1) in red_tiles.iter().enumerate() {
+            let tile2 = red_tiles[(i + 1) % red_tiles.len()];
+
+            let (col1, row1) = (xs.squash(tile1.0), ys.squash(tile1.1));
+            let (col2, row2) = (xs.squash(tile2.0), ys.squash(tile2.1));
+
+            for row in row1.min(row2) ..= row1.max(row2) {
+            for col in col1.min(col2) ..= col1.max(col2) {
+                cells[(row, col)] = Cell::Loop;
+            }}
+        }
+
+        // flood the outside in from the corner
+        let mut pending = vec![(0, 0)];
+        cells[(0, 0)] = Cell::Outside;
+
+        while let Some((row, col)) = pending.pop() {
+            for coord in cells.neighbours(row, col, &FOUR).collect::<Vec<_>>() {
+                if cells[coord] == Cell::Unknown {
+                    cells[coord] = Cell::Outside;
+                    pending.push(coord);
+                }
+            }
+        }
+
+        // prefix sums, with an extra row and column of zeroes at the top and left
+        let mut outside = Grid::new(cells.width() + 1, cells.height() + 1, 0);
+
+        for ((row, col), &cell) in cells.iter() {
+            outside[(row+1, col+1)] = (cell == Cell::Outside) as u32
+                                    + outside[(row, col+1)]
+                                    + outside[(row+1, col)]
+                                    - outside[(row, col)];
+        }
+
+        Floor { xs, ys, outside }
+    }
+
+    // whether the rectangle with these red tiles at opposite corners has no tiles outside the loop
+    fn is_inside(&self, tile1: (u32, u32), tile2: (u32, u32)) -> bool {
+        let (col1, row1) = (self.xs.squash(tile1.0), self.ys.squash(tile1.1));
+        let (col2, row2) = (self.xs.squash(tile2.0), self.ys.squash(tile2.1));
+
+        let (top, bottom) = (row1.min(row2), row1.max(row2) + 1);
+        let (left, right) = (col1.min(col2), col1.max(col2) + 1);
+
+        let outside = self.outside[(bottom, right)] + self.outside[(top, left)]
+                    - self.outside[(top, right)]    - self.outside[(bottom, left)];
+
+        outside == 0
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Unknown,
+    Loop,
+    Outside
+}
+
+// the distinct x (or y) coordinates of the red tiles, and where each one ends up on the squashed
+// floor. there's a gap before the first and after the last, so the outside is all one piece, and
+// between two coordinates only if there are tiles between them. an empty gap would be a way round
+// the outside of the loop that isn't really there, through a notch no wider than a line
+struct Axis {
+    values  : Vec<u32>,
+    squashed: Vec<usize>
+}
+
+impl Axis {
+    fn new(values: impl Iterator<Item = u32>) -> Self {
+        let mut values: Vec<u32> = values.collect();
+        values.sort();
+        values.dedup();
+
+        let mut squashed = Vec::with_capacity(values.len());
+        let mut at = 1;
+
+        for (i, &value) in values.iter().enumerate() {
+            if i > 0 {
+                at += if value > values[i-1] + 1 { 2 } else { 1 };
+            }
+            squashed.push(at);
+        }
+
+        Axis { values, squashed }
+    }
+
+    // how many columns (or rows) the squashed floor has, counting the gaps at each end
+    fn len(&self) -> usize {
+        self.squashed.last().map_or(1, |last| last + 2)
+    }
+
+    fn squash(&self, value: u32) -> usize {
+        self.squashed[self.values.binary_search(&value).unwrap()]
+    }
+}
+
+
+// ChatGPT 5.1 mostly
+fn area_between(tile1: (u32, u32), tile2: (u32, u32)) -> usize {
+    let dx = tile1.0 as i64 - tile2.0 as i64;
+    let dy = tile1.1 as i64 - tile2.1 as i64;
+
+    (dx.unsigned_abs() as usize + 1) * (dy.unsigned_abs() as usize + 1)
+}
+
+
+/* Drawing */
+
+// the longer side of the floor is drawn this many pixels long, with room around it for the labels
+const DRAWING: f64 = 800.0;
+const MARGIN:  f64 = 60.0;
+
+// an SVG of the floor: the loop through the red tiles with the green tiles inside it shaded, the
+// red tiles themselves, and the rectangle each part picked with its corner tiles labelled. one
+// element to a line, so two drawings can be diffed as text
+pub fn svg(input: &Input) -> String {
+    let tiles = &input.red_tiles;
+
+    let left   = tiles.iter().map(|tile| tile.0).min().unwrap_or(0);
+    let right  = tiles.iter().map(|tile| tile.0).max().unwrap_or(0);
+    let top    = tiles.iter().map(|tile| tile.1).min().unwrap_or(0);
+    let bottom = tiles.iter().map(|tile| tile.1).max().unwrap_or(0);
+
+    let scale  = DRAWING / ((right - left).max(bottom - top) + 1) as f64;
+    let width  = 2.0 * MARGIN + (right - left + 1) as f64 * scale;
+    let height = 2.0 * MARGIN + (bottom - top + 1) as f64 * scale;
+
+    // the top left corner of a tile in the drawing
+    let x = |x: u32| MARGIN + (x - left) as f64 * scale;
+    let y = |y: u32| MARGIN + (y - top)  as f64 * scale;
+
+    // a red tile is drawn at least a few pixels wide, even when it's much less than a pixel
+    let size = scale.max(4.0);
+    let nudge = (scale - size) / 2.0;
+
+    let mut svg = String::new();
+
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="0 0 {width:.1} {height:.1}">"#).unwrap();
+    writeln!(svg, "<style>").unwrap();
+    writeln!(svg, "  .loop {{ fill: #cfc; stroke: #393; stroke-width: 2 }}").unwrap();
+    writeln!(svg, "  .tile {{ fill: #d22 }}").unwrap();
+    writeln!(svg, "  rect.part1 {{ fill: none; stroke: #24c; stroke-width: 3; stroke-dasharray: 8 4 }}").unwrap();
+    writeln!(svg, "  rect.part2 {{ fill: none; stroke: #e80; stroke-width: 3 }}").unwrap();
+    writeln!(svg, "  text.part1 {{ fill: #24c }}").unwrap();
+    writeln!(svg, "  text.part2 {{ fill: #e80 }}").unwrap();
+    writeln!(svg, "  text {{ font: 14px sans-serif }}").unwrap();
+    writeln!(svg, "</style>").unwrap();
+    writeln!(svg, r##"<rect width="100%" height="100%" fill="#fff"/>"##).unwrap();
+
+    // the loop runs through the middle of the red tiles
+    let points: Vec<String> = tiles.iter()
+        .map(|&(tx, ty)| format!("{:.1},{:.1}", x(tx) + scale / 2.0, y(ty) + scale / 2.0))
+        .collect();
+
+    writeln!(svg, r#"<polygon class="loop" points="{}"/>"#, points.join(" ")).unwrap();
+
+    for &(tx, ty) in tiles {
+        writeln!(svg, r#"<rect class="tile" x="{:.1}" y="{:.1}" width="{size:.1}" height="{size:.1}"/>"#,
+                 x(tx) + nudge, y(ty) + nudge).unwrap();
+    }
+
+    let picks = [("part1", largest_rectangle(input)),
+                 ("part2", largest_rectangle_inside(input))];
+
+    let mut labelled = vec![];
+
+    for (row, (part, pick)) in picks.into_iter().enumerate() {
+        let Some((tile1, tile2)) = pick else { continue };
+
+        let (x1, x2) = (tile1.0.min(tile2.0), tile1.0.max(tile2.0));
+        let (y1, y2) = (tile1.1.min(tile2.1), tile1.1.max(tile2.1));
+
+        writeln!(svg, r#"<rect class="{part}" x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}"/>"#,
+                 x(x1), y(y1), (x2 - x1 + 1) as f64 * scale, (y2 - y1 + 1) as f64 * scale).unwrap();
+
+        writeln!(svg, r#"<text class="{part}" x="10" y="{}">part {}: {}</text>"#,
+                 20 * (row + 1), row + 1, area_between(tile1, tile2)).unwrap();
+
+        // each label sits off the outside corner of the rectangle
+        for (tx, ty) in [tile1, tile2] {
+            if labelled.contains(&(tx, ty)) {
+                continue
+            }
+            labelled.push((tx, ty));
+
+            let (lx, anchor) = if tx == x1 { (x(tx) - 4.0, "end") } else { (x(tx) + scale + 4.0, "start") };
+            let  ly          = if ty == y1 { y(ty) - 4.0 } else { y(ty) + scale + 16.0 };
+
+            writeln!(svg, r#"<text x="{lx:.1}" y="{ly:.1}" text-anchor="{anchor}">{tx},{ty}</text>"#).unwrap();
+        }
+    }
+
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+
+/* Parsing */
+
+// ChatGPT 5.1 mostly
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
+
+    let red_tiles = text
+        .lines()
+        .map(|line| {
+            let (x, y) = source.split_once(line, ",", "a red tile like 7,1")?;
+            Ok((source.parse::<u32>(x, "a column number")?,
+                source.parse::<u32>(y, "a row number")?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    // part 2 shades in the inside of the loop, which only makes sense if there is one. the error
+    // points at the first flaw and lists the rest, so one go at fixing the input finds them all
+    if let Err(flaws) = polygon::check(&red_tiles) {
+        let lines = text.lines().collect::<Vec<_>>();
+        let flaw  = &flaws[0];
+
+        let mut expected = flaw.expected(lines.len());
+        if flaws.len() > 1 {
+            let rest = flaws[1..].iter().map(|flaw| flaw.to_string()).collect::<Vec<_>>();
+            expected += &format!(" (and {} more: {})", rest.len(), rest.join("; "));
+        }
+
+        return Err(match flaw.point() {
+            Some(point) => source.error(lines[point], expected),
+            None        => source.end(expected)
+        })
+    }
+
+    Ok(Input { red_tiles })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+    use common::day_dir;
+    use common::random::Lcg;
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day09>(1, "sample")
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        check::<Day09>(2, "sample")
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day09>(1, "input")
+    }
+
+    #[test]
+    fn test_part2() {
+        check::<Day09>(2, "input")
+    }
+
+    // the original search, every tile paired with every other
+    fn part1_all_pairs(input: &Input) -> usize {
+        let mut largest_area = 0_usize;
+
+        for tile1 in &input.red_tiles {
+        for tile2 in &input.red_tiles {
+            let area = area_between(*tile1, *tile2);
+            if area > largest_area {
+                largest_area = area;
+            }
+        }}
+
+        largest_area
+    }
+
+    #[test]
+    fn test_part1_matches_all_pairs() {
+        let mut random = Lcg::new(12345);
+
+        // small floors to get plenty of shared rows and columns, big ones for realistic spreads
+        for round in 0..200 {
+            let size  = [5, 50, 100_000][round % 3];
+            let count = 1 + random.below(60) as usize;
+
+            let red_tiles = (0..count).map(|_| (random.below(size), random.below(size))).collect();
+            let input     = Input { red_tiles };
+
+            assert_eq!(part1_all_pairs(&input), part1(&input), "{:?}", input.red_tiles);
+        }
+    }
+
+    #[test]
+    fn test_frontier() {
+        let tiles = [(0, 2), (1, 1), (2, 0), (2, 2), (1, 2)];
+
+        assert_eq!(vec![(0, 2), (1, 1), (2, 0)], frontier(&tiles, false, false));
+        assert_eq!(vec![(2, 2)],                 frontier(&tiles, true,  true ));
+        assert_eq!(vec![(0, 2)],                 frontier(&tiles, false, true ));
+        assert_eq!(vec![(2, 0)],                 frontier(&tiles, true,  false));
+    }
+
+    #[test]
+    fn test_largest_rectangles() {
+        let input = Day09::load(day_dir(9).join("sample.txt")).unwrap();
+
+        assert_eq!(Some(((2, 3), (11, 7))), largest_rectangle(&input));
+        assert_eq!(Some(((9, 5), (2, 3))),  largest_rectangle_inside(&input));
+    }
+
+    #[test]
+    fn test_svg() {
+        let input = Day09::load(day_dir(9).join("sample.txt")).unwrap();
+        let path  = day_dir(9).join("sample.svg");
+        let svg   = svg(&input);
+
+        // AOC_UPDATE_GOLDEN rewrites the drawing after a deliberate change
+        if std::env::var_os("AOC_UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, &svg).unwrap();
+        }
+
+        let expected = std::fs::read_to_string(&path).unwrap();
+        assert!(expected == svg, "the drawing of the sample doesn't match {}", path.display());
+    }
+
+    #[test]
+    fn test_parse_needs_a_loop() {
+        let error = parse_input("7,1\n11,1\n11,7\n9,5\n2,5\n2,3\n7,3\n").err().unwrap();
+
+        assert_eq!((4, "9,5"), (error.line, error.found.as_str()));
+        assert_eq!("a point in the same row or column as line 3", error.expected);
+
+        let error = parse_input("7,1\n11,1\n").err().unwrap();
+        assert!(error.expected.starts_with("at least 4 points to make a loop"));
+
+        // two separate wrong turns, both of them reported
+        let error = parse_input("7,1\n11,1\n11,7\n9,6\n9,5\n2,5\n3,3\n7,3\n").err().unwrap();
+        assert_eq!((4, "9,6"), (error.line, error.found.as_str()));
+        assert_eq!("a point in the same row or column as line 3 \
+                    (and 1 more: lines 6 and 7 share neither a row nor a column)", error.expected);
+    }
+
+    #[test]
+    fn test_part2_through_a_notch() {
+        // the notch between x = 5 and 6 has no tiles in it, so it doesn't let the outside in
+        let input = parse_input("0,0\n10,0\n10,10\n6,10\n6,2\n5,2\n5,10\n0,10\n").unwrap();
+
+        assert_eq!(121, part2(&input));
+    }
+
+    #[test]
+    fn test_part2_stays_inside() {
+        // a U shape: the whole square has red corners, but the notch between the arms is outside
+        // the loop, so the best left is the band along the bottom
+        let input = parse_input("0,0\n2,0\n2,5\n6,5\n6,0\n8,0\n8,8\n0,8\n").unwrap();
+
+        assert_eq!(81, part1(&input));
+        assert_eq!(28, part2(&input));
+    }
+
+}
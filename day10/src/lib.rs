@@ -1,28 +1,34 @@
 // https://adventofcode.com/2025/day/10
 
-fn main() {
-    let sample = parse_input("sample.txt");
-    let input  = parse_input("input.txt");
-
-    println!("Sample 1: {}", part1(&sample));
-    println!("Sample 2: {}", part2(&sample));
-    println!("Part 1:   {}", part1(&input));
-    println!("Part 2:   {}", part2(&input));
+use common::{Answer, Solution, Unsolved};
+use common::parse::{ParseError, Source};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(_input: &Input) -> impl Answer            { Unsolved }
 }
 
-struct Input {
+pub struct Input {
     machines: Vec<Machine>
 }
 
 struct Machine {
     indicator_lights: String,
     buttons: Vec<Vec<u32>>,
+    #[allow(dead_code)] // for part 2
     joltages: Vec<u32>,
 }
 
 // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
 
-fn part1(input: &Input) -> usize {
+pub fn part1(input: &Input) -> usize {
     let mut total_fewest_presses = 0;
     
     let fewest_buttons =
@@ -68,13 +74,9 @@ fn part1(input: &Input) -> usize {
     total_fewest_presses
 }
 
-fn part2(_input: &Input) -> u64 {
-    0
-}
 
-
-fn get_lights_on_after_pressing_buttons(button_indexes: &[u8], buttons: &Vec<Vec<u32>>) -> u32 {
-    let mut lights_on = 0 as u32;
+fn get_lights_on_after_pressing_buttons(button_indexes: &[u8], buttons: &[Vec<u32>]) -> u32 {
+    let mut lights_on = 0_u32;
 
     for idx in button_indexes {
         for b in &buttons[*idx as usize] {
@@ -132,23 +134,36 @@ fn indicator_lights_to_target(indicator_lights: &str) -> u32 {
 
 /* Parsing (mostly ChatGPT 5.1) */
 
-fn parse_input(path: &str) -> Input {
-    let machines = std::fs::read_to_string(path)
-        .unwrap()
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
+
+    let machines: Vec<Machine> = text
         .lines()
-        .map(parse_line)
-        .collect();
+        .map(|line| parse_line(&source, line))
+        .collect::<Result<_, _>>()?;
+
+    if machines.is_empty() {
+        return Err(source.end("a machine like [.##.] (3) (1,3) {3,5,4,7}"))
+    }
 
-    Input { machines }
+    Ok(Input { machines })
 }
 
-fn parse_line(line: &str) -> Machine {
+fn parse_line(source: &Source, line: &str) -> Result<Machine, ParseError> {
     // [indicator] rest...
-    let start = line.find('[').unwrap();
-    let end = line[start..].find(']').unwrap() + start;
+    let start = line.find('[')
+                    .ok_or_else(|| source.error(line, "indicator lights like [.##.]"))?;
+    let end = line[start..].find(']')
+                           .ok_or_else(|| source.error(&line[start..], "a closing ]"))? + start;
 
     // between [ and ]
-    let indicator_lights = line[start + 1..end].to_string();
+    let lights = &line[start + 1..end];
+
+    if let Some(i) = lights.find(|c| c != '.' && c != '#') {
+        return Err(source.error(&lights[i..], "a light that's off '.' or on '#'"))
+    }
+
+    let indicator_lights = lights.to_string();
 
     // after the closing ]
     let rest = line[end + 1..].trim();
@@ -158,76 +173,67 @@ fn parse_line(line: &str) -> Machine {
 
     for token in rest.split_whitespace() {
         if token.starts_with('(') {
-            buttons.push(parse_numbers(token, '(', ')'));
+            buttons.push(parse_numbers(source, token, '(', ')')?);
         } else if token.starts_with('{') {
-            joltages = parse_numbers(token, '{', '}');
+            joltages = parse_numbers(source, token, '{', '}')?;
+        } else {
+            return Err(source.error(token, "a button like (1,3) or joltages like {3,5,4,7}"))
         }
     }
 
-    Machine {
+    Ok(Machine {
         indicator_lights,
         buttons,
         joltages,
-    }
+    })
 }
 
-fn parse_numbers(token: &str, open: char, close: char) -> Vec<u32> {
-    let inner = token
-        .trim()
-        .trim_start_matches(open)
-        .trim_end_matches(close);
+fn parse_numbers(source: &Source, token: &str, open: char, close: char) -> Result<Vec<u32>, ParseError> {
+    let Some(inner) = token.strip_prefix(open).and_then(|token| token.strip_suffix(close)) else {
+        return Err(source.error(token, format!("a list of numbers between {open} and {close}")))
+    };
 
     if inner.is_empty() {
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
     inner
         .split(',')
-        .map(|s| s.parse::<u32>().unwrap()) // input always valid
+        .map(|s| source.parse::<u32>(s, "a number"))
         .collect()
 }
 
-
 /* Tests */
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::answers::check;
 
     #[test]
     fn test_sample_part1() {
-        let input = parse_input("sample.txt");
-        assert_eq!(7, part1(&input))
+        check::<Day10>(1, "sample")
     }
 
     #[test]
     fn test_sample_part2() {
-        let input = parse_input("sample.txt");
-        assert_eq!(33, part2(&input))
+        check::<Day10>(2, "sample")
     }
 
     #[test]
     fn test_part1() {
-        let input = parse_input("input.txt");
-        assert_eq!(578, part1(&input))
+        check::<Day10>(1, "input")
     }
 
     #[test]
-    fn test_part2() {
-        let input = parse_input("input.txt");
-        assert_eq!(0, part2(&input))
+    fn test_parse_error() {
+        let error = parse_input("[.##.] (3) (1,x) {3,5,4,7}\n").err().unwrap();
+
+        assert_eq!((1, 15), (error.line, error.column));
+        assert_eq!("x", error.found);
+
+        let error = parse_input("[.#] (0) {1}\n[.##.] (3) {3\n").err().unwrap();
+        assert_eq!((2, 12), (error.line, error.column));
     }
 
 }
-
-/*
-    $ time ./target/release/day10.exe
-    Sample 1: 7
-    Sample 2: 0
-    Part 1:   578
-    Part 2:   0
-
-    real    0m0.023s
-    user    0m0.000s
-    sys     0m0.000s
-*/
@@ -1,21 +1,25 @@
 // https://adventofcode.com/2025/day/11
 
-fn main() {
-    let sample = parse_input("sample.txt");
-    let sample2= parse_input("sample2.txt");
-    let input  = parse_input("input.txt");
-
-    println!("Sample 1: {}", part1(&sample));
-    println!("Sample 2: {}", part2(&sample2));
-    println!("Part 1:   {}", part1(&input));
-    println!("Part 2:   {}", part2(&input));
+use common::{Answer, Solution};
+use common::parse::{ParseError, Source};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(input: &Input) -> impl Answer             { part2(input) }
 }
 
-struct Input {
+pub struct Input {
     devices: HashMap<String, Vec<String>>
 }
 
-fn part1(input: &Input) -> u64 {
+pub fn part1(input: &Input) -> u64 {
     let mut memos: HashMap<String, u64> = HashMap::new();
 
     go( "you",
@@ -57,7 +61,7 @@ struct Memo {
     dac_and_fft: u64
 }
 
-fn part2(input: &Input) -> u64 {
+pub fn part2(input: &Input) -> u64 {
     let mut memos: HashMap<String, Memo> = HashMap::new();
 
     let memo = go2( "svr",
@@ -129,18 +133,14 @@ use std::collections::HashMap;
 /* Parsing */
 
 // ChatGPT 5.1
-fn parse_input(path: &str) -> Input {
-    let contents = std::fs::read_to_string(path)
-        .expect("File is guaranteed to exist and be valid");
-
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let source = Source::new(text);
     let mut map: HashMap<String, Vec<String>> = HashMap::new();
 
-    for line in contents.lines() {
+    for line in text.lines() {
 
         // Split "key: v1 v2 v3"
-        let (key, values_part) = line
-            .split_once(':')
-            .expect("Every line must contain ':'");
+        let (key, values_part) = source.split_once(line, ":", "a device and its outputs like aaa: you hhh")?;
 
         let key = key.trim().to_string();
 
@@ -152,7 +152,7 @@ fn parse_input(path: &str) -> Input {
         map.insert(key, values);
     }
 
-    Input { devices: map }
+    Ok(Input { devices: map })
 }
 
 
@@ -161,41 +161,26 @@ fn parse_input(path: &str) -> Input {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::answers::check;
 
     #[test]
     fn test_sample_part1() {
-        let input = parse_input("sample.txt");
-        assert_eq!(5, part1(&input))
+        check::<Day11>(1, "sample")
     }
 
     #[test]
     fn test_sample_part2() {
-        let input = parse_input("sample2.txt");
-        assert_eq!(2, part2(&input))
+        check::<Day11>(2, "sample2")
     }
 
     #[test]
     fn test_part1() {
-        let input = parse_input("input.txt");
-        assert_eq!(640, part1(&input))
+        check::<Day11>(1, "input")
     }
 
     #[test]
     fn test_part2() {
-        let input = parse_input("input.txt");
-        assert_eq!(367579641755680, part2(&input))
+        check::<Day11>(2, "input")
     }
 
 }
-
-/*
-    $ time ./target/release/day11.exe
-    Sample 1: 5
-    Sample 2: 2
-    Part 1:   640
-    Part 2:   367579641755680
-
-    real    0m0.021s
-    user    0m0.000s
-    sys     0m0.000s
-*/
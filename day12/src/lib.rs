@@ -0,0 +1,493 @@
+// https://adventofcode.com/2025/day/12
+
+use std::collections::HashSet;
+
+use common::{Answer, Solution, Unsolved};
+use common::grid::Grid;
+use common::parse::{ParseError, Source};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Input;
+
+    fn parse(text: &str) -> Result<Input, ParseError> { parse_input(text) }
+    fn part1(input: &Input) -> impl Answer             { part1(input) }
+    fn part2(_input: &Input) -> impl Answer            { Unsolved }
+}
+
+pub struct Input {
+    gift_shapes: Vec<GiftShape>,
+    regions: Vec<Region>
+}
+
+struct GiftShape {
+    tiles: Grid<char>
+}
+
+struct Region {
+    width: u32,
+    height: u32,
+    gift_counts: Vec<u32>
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.regions
+         .iter()
+         .filter(|region| can_arrange_gifts(&input.gift_shapes, region))
+         .count()
+}
+
+// whether the gifts can all be put down in the region without overlapping, turned and flipped
+// however we like. the cheap checks settle most regions, the rest are searched properly
+fn can_arrange_gifts(gift_shapes: &[GiftShape], region: &Region) -> bool {
+
+    let total_octothorpes =
+        region.gift_counts
+              .iter()
+              .enumerate()
+              .map(|(gift, count)| gift_shapes[gift].count_octothorpes() * count)
+              .sum::<u32>();
+
+    let region_area = region.width * region.height;
+
+    // not enough room even if the gifts fit together perfectly
+    if total_octothorpes > region_area {
+        return false
+    }
+
+    // enough room to give every gift a square of its own, however awkward its shape (on the
+    // real input that's 3x3 slots, and it decides every region the area check doesn't)
+    let slot = gift_shapes.iter()
+                          .map(|shape| shape.tiles.width().max(shape.tiles.height()) as u32)
+                          .max()
+                          .unwrap_or(1);
+
+    let gift_count = region.gift_counts.iter().sum::<u32>();
+
+    if (region.width / slot) * (region.height / slot) >= gift_count {
+        return true
+    }
+
+    // the search goes across then down, so it has less to keep track of across a narrow region.
+    // gifts can be flipped and turned, so a region packs the same way turned on its side
+    let width  = region.width.min(region.height) as usize;
+    let height = region.width.max(region.height) as usize;
+
+    let orientations = gift_shapes.iter()
+                                  .map(|shape| shape.orientations()
+                                                    .iter()
+                                                    .map(|offsets| Orientation::new(offsets, width))
+                                                    .collect::<Vec<_>>())
+                                  .collect::<Vec<_>>();
+
+    // how far past the first empty tile a gift can reach
+    let reach = orientations.iter()
+                            .flatten()
+                            .map(|orientation: &Orientation| (orientation.down + 1) * width)
+                            .max()
+                            .unwrap_or(0);
+
+    // every way each gift could go down on the empty region, by where its first tile is
+    let placements = orientations.iter()
+                                 .map(|turns| (0 .. width * height)
+                                     .flat_map(|first| turns.iter().map(move |turn| (first, turn)))
+                                     .filter(|&(first, turn)| turn.fits_in(first, width, height))
+                                     .collect())
+                                 .collect::<Vec<_>>();
+
+    let mut packing = Packing {
+        placements  : &placements,
+        counts      : region.gift_counts.clone(),
+        left        : gift_count,
+        floor       : vec![false; width * height],
+        slack       : (region_area - total_octothorpes) as usize,
+        reach,
+        dead_ends   : HashSet::new()
+    };
+
+    packing.fill(0)
+}
+
+impl GiftShape {
+    fn count_octothorpes(&self) -> u32 {
+        self.tiles
+            .count(|&tile| tile == '#') as u32
+    }
+
+    // the distinct ways the gift can be turned or flipped, each as offsets from its first tile
+    // (reading across then down) to the rest
+    fn orientations(&self) -> Vec<Vec<(usize, isize)>> {
+        let tiles: Vec<(isize, isize)> = self.tiles
+                                             .iter()
+                                             .filter(|&(_, &tile)| tile == '#')
+                                             .map(|((row, col), _)| (row as isize, col as isize))
+                                             .collect();
+
+        let mut orientations = vec![];
+
+        for flip in [false, true] {
+        for turns in 0..4 {
+            let mut turned: Vec<(isize, isize)> = tiles.iter()
+                .map(|&(row, col)| if flip { (row, -col) } else { (row, col) })
+                .map(|tile| (0..turns).fold(tile, |(row, col), _| (col, -row)))
+                .collect();
+
+            turned.sort();
+
+            let (first_row, first_col) = turned[0];
+            let offsets: Vec<(usize, isize)> = turned.iter()
+                .map(|&(row, col)| ((row - first_row) as usize, col - first_col))
+                .collect();
+
+            if !orientations.contains(&offsets) {
+                orientations.push(offsets);
+            }
+        }}
+
+        orientations
+    }
+}
+
+// one way of turning a gift, fitted to a region's width: where its tiles are on the floor
+// relative to its first one, and how far it sticks out to each side and down from there
+struct Orientation {
+    tiles: Vec<usize>,
+    left : usize,
+    right: usize,
+    down : usize
+}
+
+impl Orientation {
+    fn new(offsets: &[(usize, isize)], width: usize) -> Self {
+        let left  = offsets.iter().map(|&(_, across)| across).min().unwrap_or(0);
+        let right = offsets.iter().map(|&(_, across)| across).max().unwrap_or(0);
+
+        Orientation {
+            tiles: offsets.iter().map(|&(down, across)| (down * width).wrapping_add_signed(across)).collect(),
+            left : left.unsigned_abs(),
+            right: right as usize,
+            down : offsets.iter().map(|&(down, _)| down).max().unwrap_or(0)
+        }
+    }
+
+    // whether it stays inside the region with its first tile here
+    fn fits_in(&self, first: usize, width: usize, height: usize) -> bool {
+        let (row, col) = (first / width, first % width);
+
+        col >= self.left && col + self.right < width && row + self.down < height
+    }
+}
+
+// a search for a way to put the gifts down, going across then down the region. each empty tile
+// it comes to either gets the first tile of a gift or is left empty for good, and only so many
+// can be left empty (the slack) before the gifts can't fit in what's left
+struct Packing<'a> {
+    placements  : &'a [Vec<(usize, &'a Orientation)>],
+    counts      : Vec<u32>,
+    left        : u32,
+    floor       : Vec<bool>,
+    slack       : usize,
+
+    // everything before the first empty tile is settled, and no gift reaches further past it
+    // than this, so the tiles in reach and the gifts left are all that matter to what happens
+    // next. the same few gifts can be put down in many different orders to get there, so
+    // remember the ones that didn't work out
+    reach       : usize,
+    dead_ends   : HashSet<(usize, Vec<u64>, Vec<u32>)>
+}
+
+impl Packing<'_> {
+    fn fill(&mut self, from: usize) -> bool {
+        if self.left == 0 {
+            return true
+        }
+
+        let Some(at) = (from..self.floor.len()).find(|&tile| !self.floor[tile]) else {
+            return false
+        };
+
+        let mut in_reach = vec![0_u64; self.reach.div_ceil(64)];
+        for tile in at .. (at + self.reach).min(self.floor.len()) {
+            if self.floor[tile] {
+                in_reach[(tile - at) / 64] |= 1 << ((tile - at) % 64);
+            }
+        }
+
+        let state = (at, in_reach, self.counts.clone());
+        if self.dead_ends.contains(&state) || self.stranded(at) > self.slack {
+            self.dead_ends.insert(state);
+            return false
+        }
+
+        for gift in 0..self.counts.len() {
+            if self.counts[gift] == 0 {
+                continue
+            }
+
+            let placements = &self.placements[gift];
+            let start      = placements.partition_point(|&(first, _)| first < at);
+
+            for &(first, orientation) in &placements[start..] {
+                if first > at {
+                    break
+                }
+                if !self.fits(at, orientation) {
+                    continue
+                }
+
+                self.put(at, orientation, true);
+                self.counts[gift] -= 1;
+                self.left         -= 1;
+
+                if self.fill(at + 1) {
+                    return true
+                }
+
+                self.put(at, orientation, false);
+                self.counts[gift] += 1;
+                self.left         += 1;
+            }
+        }
+
+        // or leave this tile empty
+        if self.slack > 0 {
+            self.slack -= 1;
+            self.floor[at] = true;
+
+            if self.fill(at + 1) {
+                return true
+            }
+
+            self.floor[at] = false;
+            self.slack += 1;
+        }
+
+        self.dead_ends.insert(state);
+        false
+    }
+
+    // whether a gift that stays inside the region with its first tile here would land on
+    // empty floor
+    fn fits(&self, first: usize, orientation: &Orientation) -> bool {
+        orientation.tiles.iter().all(|&tile| !self.floor[first + tile])
+    }
+
+    fn put(&mut self, first: usize, orientation: &Orientation, down: bool) {
+        for &tile in &orientation.tiles {
+            self.floor[first + tile] = down;
+        }
+    }
+
+    // how many empty tiles from here on none of the gifts left could cover anymore. they'll
+    // have to be left empty, so if there are more than the slack there's no point going on
+    fn stranded(&self, at: usize) -> usize {
+        let mut coverable = vec![false; self.floor.len()];
+
+        for gift in 0..self.counts.len() {
+            if self.counts[gift] == 0 {
+                continue
+            }
+
+            let placements = &self.placements[gift];
+            let start      = placements.partition_point(|&(first, _)| first < at);
+
+            for &(first, orientation) in &placements[start..] {
+                if self.fits(first, orientation) {
+                    for &tile in &orientation.tiles {
+                        coverable[first + tile] = true;
+                    }
+                }
+            }
+        }
+
+        (at .. self.floor.len()).filter(|&tile| !self.floor[tile] && !coverable[tile])
+                                .count()
+    }
+}
+
+
+/* Parsing */
+
+pub fn parse_input(text: &str) -> Result<Input, ParseError> {
+    let contents = text.replace("\r\n", "\n");
+    let source   = Source::new(&contents);
+
+    let (top, bottom) = contents.rsplit_once("\n\n")
+                                .ok_or_else(|| source.end("the gift shapes, a blank line, then the regions"))?;
+
+    let gift_shapes: Vec<GiftShape> = top.split("\n\n")
+                                         .map(|ascii| parse_gift_shape(&source, ascii))
+                                         .collect::<Result<_, _>>()?;
+
+    let regions = bottom.lines()
+                        .map(|line| parse_region(&source, line, gift_shapes.len()))
+                        .collect::<Result<_, _>>()?;
+
+    Ok(Input {
+        gift_shapes,
+        regions
+    })
+}
+
+fn parse_gift_shape(source: &Source, ascii: &str) -> Result<GiftShape, ParseError> {
+    let mut lines = ascii.lines();
+
+    let header = lines.next().unwrap_or(ascii);
+    if !header.ends_with(':') {
+        return Err(source.error(header, "a gift shape's index like 0:"))
+    }
+
+    let tiles = Grid::parse_lines(source,
+                                  lines,
+                                  |c| matches!(c, '#' | '.').then_some(c),
+                                  "a gift tile '#' or '.'")?;
+
+    if tiles.count(|&tile| tile == '#') == 0 {
+        return Err(source.error(ascii, "a gift shape with at least one '#'"))
+    }
+
+    Ok(GiftShape { tiles })
+}
+
+fn parse_region(source: &Source, ascii: &str, shape_count: usize) -> Result<Region, ParseError> {
+    let (left, right) = source.split_once(ascii, ": ", "a region like 12x5: 1 0 1 0 2 2")?;
+    let (width, height) = source.split_once(left, "x", "a region size like 12x5")?;
+
+    let gift_counts: Vec<u32> = right.split_whitespace()
+                                     .map(|digits| source.parse(digits, "a number of gifts"))
+                                     .collect::<Result<_, _>>()?;
+
+    if gift_counts.len() > shape_count {
+        return Err(source.error(right, format!("at most {shape_count} gift counts, one per shape")))
+    }
+
+    Ok(Region {
+        width : source.parse(width, "a region width")?,
+        height: source.parse(height, "a region height")?,
+        gift_counts
+    })
+}
+
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::check;
+    use common::day_dir;
+    use common::random::Lcg;
+
+    #[test]
+    fn test_sample_part1() {
+        check::<Day12>(1, "sample")
+    }
+
+    #[test]
+    fn test_part1() {
+        check::<Day12>(1, "input")
+    }
+
+    #[test]
+    fn test_can_arrange_gifts() {
+        let input = Day12::load(day_dir(12).join("sample.txt")).unwrap();
+
+        let arranged: Vec<bool> = input.regions
+                                       .iter()
+                                       .map(|region| can_arrange_gifts(&input.gift_shapes, region))
+                                       .collect();
+
+        assert_eq!(vec![true, true, false], arranged);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let input = Day12::load(day_dir(12).join("sample.txt")).unwrap();
+
+        let counts: Vec<usize> = input.gift_shapes
+                                      .iter()
+                                      .map(|shape| shape.orientations().len())
+                                      .collect();
+
+        assert_eq!(vec![8, 8, 2, 4, 4, 2], counts);
+
+        // the C shape, opening left: ###/..#/###
+        assert!(input.gift_shapes[4].orientations()
+                                    .contains(&vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 0), (2, 1), (2, 2)]));
+    }
+
+    // every gift tried everywhere, with none of the shortcuts
+    fn can_arrange_by_hand(orientations: &[Vec<Vec<(usize, isize)>>], counts: &mut [u32], floor: &mut Grid<bool>) -> bool {
+        let Some(gift) = counts.iter().position(|&count| count > 0) else {
+            return true
+        };
+
+        counts[gift] -= 1;
+
+        for ((row, col), _) in floor.clone().iter() {
+        for offsets in &orientations[gift] {
+            let tiles: Option<Vec<(usize, usize)>> = offsets.iter()
+                .map(|&(down, across)| Some((row + down, col.checked_add_signed(across)?)))
+                .map(|tile| tile.filter(|&(row, col)| row < floor.height() && col < floor.width() && !floor[(row, col)]))
+                .collect();
+
+            let Some(tiles) = tiles else { continue };
+
+            tiles.iter().for_each(|&tile| floor[tile] = true);
+
+            if can_arrange_by_hand(orientations, counts, floor) {
+                return true
+            }
+
+            tiles.iter().for_each(|&tile| floor[tile] = false);
+        }}
+
+        counts[gift] += 1;
+        false
+    }
+
+    #[test]
+    fn test_can_arrange_gifts_by_hand() {
+        let input        = Day12::load(day_dir(12).join("sample.txt")).unwrap();
+        let orientations = input.gift_shapes.iter().map(GiftShape::orientations).collect::<Vec<_>>();
+
+        let mut random = Lcg::new(12345);
+
+        for _ in 0..60 {
+            let width  = 3 + random.below(5);
+            let height = 3 + random.below(3);
+
+            let mut gift_counts = vec![0; 6];
+            for _ in 0 .. 1 + random.below(3) {
+                gift_counts[random.below(6) as usize] += 1;
+            }
+
+            let region   = Region { width, height, gift_counts };
+            let expected = can_arrange_by_hand(&orientations,
+                                               &mut region.gift_counts.clone(),
+                                               &mut Grid::new(width as usize, height as usize, false));
+
+            assert_eq!(expected, can_arrange_gifts(&input.gift_shapes, &region),
+                       "{width}x{height}: {:?}", region.gift_counts);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("0:\n##\n\n4by4: 1\n").err().unwrap();
+
+        assert_eq!((4, 1), (error.line, error.column));
+        assert_eq!("4by4", error.found);
+
+        // a truncated input with no regions
+        let error = parse_input("0:\n##\n").err().unwrap();
+        assert_eq!("", error.found);
+
+        let error = parse_input("0:\n..\n\n1:\n#.\n\n4x4: 1 1\n").err().unwrap();
+        assert_eq!((1, "a gift shape with at least one '#'"), (error.line, error.expected.as_str()));
+    }
+
+}